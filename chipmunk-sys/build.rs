@@ -1,14 +1,22 @@
-#![allow(unused_imports, dead_code, unused_variables)]
+#![allow(unused_imports, unused_mut, dead_code, unused_variables)]
 
 #[cfg(feature = "bindgen")]
 extern crate bindgen;
 
+extern crate cc;
+
+#[cfg(feature = "pkg-config")]
+extern crate pkg_config;
+
 use std::path::{Path, PathBuf};
 use std::{env, fs, io};
 
 // corresponds to the headers that we have in chipmunk-sys/chipmunk-{version}
 const CHIPMUNK_HEADERS_BUNDLED_VERSION: &str = "7.0.3";
 
+// chipmunk's cpFloat is a float instead of a double with the "f32" feature
+const CP_USE_DOUBLES: &str = if cfg!(feature = "f32") { "0" } else { "1" };
+
 // means the lastest stable version that can be downloaded from chipmunk's source
 const LASTEST_CHIPMUNK_VERSION: &str = "7.0.3";
 
@@ -32,9 +40,29 @@ fn compute_include_paths() -> Vec<String> {
         }
     }
 
+    #[cfg(feature = "use-pkg-config")]
+    {
+        // same as above, the linking parameters are printed by link_chipmunk()
+        if let Ok(pkg_config_library) = pkg_config::Config::new()
+            .cargo_metadata(false)
+            .probe("chipmunk")
+        {
+            for path in pkg_config_library.include_paths {
+                include_paths.push(format!("{}", path.display()));
+            }
+        }
+    }
+
     include_paths
 }
 
+fn get_bundled_source_path() -> PathBuf {
+    let mut source_path: PathBuf = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    source_path.push(format!("chipmunk-{}", CHIPMUNK_HEADERS_BUNDLED_VERSION));
+    source_path.push("src");
+    source_path
+}
+
 fn get_bundled_header_path() -> PathBuf {
     let mut include_path: PathBuf = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
     include_path.push(format!("chipmunk-{}", CHIPMUNK_HEADERS_BUNDLED_VERSION));
@@ -43,17 +71,89 @@ fn get_bundled_header_path() -> PathBuf {
 }
 
 #[cfg(feature = "use-vcpkg")]
-fn get_vcpkg_config() {
-    vcpkg::find_package("chipmunk").unwrap();
+fn get_vcpkg_config() -> Result<vcpkg::Library, vcpkg::Error> {
+    vcpkg::find_package("chipmunk")
+}
+
+#[cfg(feature = "use-pkg-config")]
+fn get_pkg_config() -> Result<pkg_config::Library, pkg_config::Error> {
+    // the chipmunk crate checks at runtime that the library is exactly the version of the headers
+    pkg_config::Config::new()
+        .exactly_version(CHIPMUNK_HEADERS_BUNDLED_VERSION)
+        .statik(link_statically())
+        .probe("chipmunk")
 }
 
-fn link_chipmunk(target_os: &str) {
+// CHIPMUNK_STATIC=1 or CHIPMUNK_STATIC=0 overrides the "static-link" feature
+fn link_statically() -> bool {
+    println!("cargo:rerun-if-env-changed=CHIPMUNK_STATIC");
+
+    match env::var("CHIPMUNK_STATIC") {
+        Ok(value) => value != "0",
+        Err(_) => cfg!(feature = "static-link"),
+    }
+}
+
+// returns true if chipmunk is linked as a shared library
+fn link_chipmunk(target_os: &str) -> bool {
+    println!("cargo:rerun-if-env-changed=CHIPMUNK_LIB_DIR");
+
+    // an explicit library directory takes priority over every other way of finding chipmunk
+    if let Ok(lib_dir) = env::var("CHIPMUNK_LIB_DIR") {
+        let kind = if link_statically() { "static" } else { "dylib" };
+        println!("cargo:rustc-link-search=native={}", lib_dir);
+        println!("cargo:rustc-link-lib={}=chipmunk", kind);
+        if kind == "static" && !target_os.contains("windows") {
+            println!("cargo:rustc-link-lib=m");
+        }
+        return kind == "dylib";
+    }
+
+    #[cfg(feature = "bundled")]
+    {
+        // vcpkg still takes priority on windows when both are enabled, which is the default
+        if !(cfg!(feature = "use-vcpkg") && target_os.contains("windows")) {
+            compile_bundled(target_os);
+            return false;
+        }
+    }
+
+    // why each enabled way of finding an installed chipmunk failed
+    let mut errors: Vec<String> = vec![];
+
+    #[cfg(feature = "use-pkg-config")]
+    {
+        match get_pkg_config() {
+            Ok(_) => return !link_statically(),
+            Err(err) => errors.push(format!("pkg-config: {}", err)),
+        }
+    }
+
+    // prints the appropriate linking parameters when found
     #[cfg(feature = "use-vcpkg")]
     {
-        // prints the appropriate linking parameters when using pkg-config
-        // useless when using "bundled"
-        get_vcpkg_config();
+        match get_vcpkg_config() {
+            Ok(library) => return !library.is_static,
+            Err(err) => errors.push(format!("vcpkg: {}", err)),
+        }
     }
+
+    // falls back to the bundled sources, so that nothing has to be installed
+    if get_bundled_source_path().is_dir() {
+        compile_bundled(target_os);
+        return false;
+    }
+
+    if !errors.is_empty() {
+        panic!(
+            "Couldn't find chipmunk, and the bundled sources are missing from {}.\n{}",
+            get_bundled_source_path().display(),
+            errors.join("\n")
+        );
+    }
+
+    // nothing was linked, the library has to be passed to the linker some other way
+    false
 }
 
 fn find_cargo_target_dir() -> PathBuf {
@@ -106,6 +206,10 @@ fn main() {
     let host = env::var("HOST").expect("Cargo build scripts always have HOST");
     let target_os = get_os_from_triple(target.as_str()).unwrap();
 
+    // the env and source directives printed below replace cargo's default of rerunning on any change
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(chipmunk_bundled)");
+
     #[cfg(not(feature = "bindgen"))]
     {
         copy_pregenerated_bindings();
@@ -113,20 +217,174 @@ fn main() {
     }
 
     #[cfg(feature = "bindgen")]
+    let include_paths: Vec<String> = compute_include_paths();
+    #[cfg(not(feature = "bindgen"))]
+    let include_paths: Vec<String> = vec![];
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(target.as_str(), host.as_str(), include_paths.as_slice());
+
+    let linked_dynamically = link_chipmunk(target_os);
+
+    // the functions in chipmunk_private.h are not marked CP_EXPORT, so chipmunk.dll doesn't export them
+    #[cfg(feature = "private-api")]
+    {
+        if linked_dynamically && target_os.contains("windows") {
+            panic!(
+                "The \"private-api\" feature needs Chipmunk to be linked statically on Windows. \
+                 Enable \"bundled\" or \"static-link\", or use a static vcpkg triplet."
+            );
+        }
+    }
+
+    compile_inline_functions(include_paths.as_slice());
+
+    // compiled after chipmunk so that the linker can resolve its calls to cpMessage()
+    #[cfg(feature = "message-handler")]
+    compile_message_handler();
+}
+
+// compiles the chipmunk sources from chipmunk-sys/chipmunk-{version}/src into a static library
+// used by the "bundled" feature, and when no installed chipmunk is found
+fn compile_bundled(target_os: &str) {
+    let source_path = get_bundled_source_path();
+    if !source_path.is_dir() {
+        panic!(
+            "The \"bundled\" feature needs the Chipmunk {} sources in {}. \
+             Copy the src/ directory of the Chipmunk2D release there, or use vcpkg instead.",
+            CHIPMUNK_HEADERS_BUNDLED_VERSION,
+            source_path.display()
+        );
+    }
+    println!("cargo:rerun-if-changed={}", source_path.display());
+
+    let mut sources: Vec<PathBuf> = fs::read_dir(&source_path)
+        .expect("Couldn't read the bundled chipmunk sources!")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "c"))
+        .collect();
+    sources.sort();
+
+    // chipmunk.c holds the default cpMessage(), which the message handler replaces
+    #[cfg(feature = "message-handler")]
     {
-        let include_paths: Vec<String> = compute_include_paths();
-        generate_bindings(target.as_str(), host.as_str(), include_paths.as_slice())
+        let chipmunk_c = source_path.join("chipmunk.c");
+        let without_message = remove_default_message(&chipmunk_c);
+        for path in sources.iter_mut() {
+            if *path == chipmunk_c {
+                *path = without_message.clone();
+            }
+        }
     }
 
-    link_chipmunk(target_os);
+    bundled_build(target_os)
+        .include(&source_path)
+        .files(sources)
+        .compile("chipmunk");
+    println!("cargo:rustc-cfg=chipmunk_bundled");
+
+    if !target_os.contains("windows") {
+        println!("cargo:rustc-link-lib=m");
+    }
+}
+
+#[cfg(feature = "message-handler")]
+// writes a copy of chipmunk.c whose cpMessage() definition is renamed, so that the assertions in it
+// still call the cpMessage() of the message handler, and returns its path
+fn remove_default_message(chipmunk_c: &Path) -> PathBuf {
+    let source = fs::read_to_string(chipmunk_c).expect("Couldn't read the bundled chipmunk.c!");
+    let definition = "\ncpMessage(";
+    if !source.contains(definition) {
+        panic!(
+            "Couldn't find the definition of cpMessage() in {}",
+            chipmunk_c.display()
+        );
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("chipmunk.c");
+    fs::write(
+        &out_path,
+        source.replacen(definition, "\ncpMessageDefault(", 1),
+    )
+    .expect("Couldn't write the modified chipmunk.c!");
+    out_path
+}
+
+fn bundled_build(target_os: &str) -> cc::Build {
+    let mut build = cc::Build::new();
+    build
+        .include(get_bundled_header_path())
+        .define("CP_USE_DOUBLES", CP_USE_DOUBLES)
+        .warnings(false)
+        // allows a Rust panic raised by the message handler to unwind through chipmunk
+        .flag_if_supported("-fexceptions");
+
+    if !target_os.contains("windows") {
+        build.flag_if_supported("-std=gnu99");
+    }
+
+    // chipmunk's debug checks are expensive, so only keep them in debug builds like its cmake build does
+    if env::var("PROFILE").map_or(true, |profile| profile == "release") {
+        build.define("NDEBUG", None);
+    }
+
+    // the bundled library is always linked statically, static-link also selects the static C runtime on msvc
+    if cfg!(feature = "static-link") {
+        build.static_crt(true);
+    }
+
+    build
+}
+
+#[cfg(feature = "message-handler")]
+// compiles the cpMessage() replacement that forwards Chipmunk's assertion messages to Rust
+// the chipmunk library being linked must be built without chipmunk.c's own cpMessage()
+fn compile_message_handler() {
+    println!("cargo:rerun-if-changed=src/message.c");
+
+    cc::Build::new()
+        .file("src/message.c")
+        // allows a Rust panic raised by the handler to unwind back out through this function
+        .flag_if_supported("-fexceptions")
+        .compile("chipmunk_message");
+}
+
+// compiles non-inline copies of the static inline functions in the chipmunk headers, which bindgen skips
+// they are built against the same headers as the bindings so that the struct layouts agree
+fn compile_inline_functions(headers_paths: &[String]) {
+    println!("cargo:rerun-if-changed=src/inline.c");
+
+    let mut build = cc::Build::new();
+    if headers_paths.is_empty() {
+        build.include(get_bundled_header_path());
+    } else {
+        for headers_path in headers_paths {
+            build.include(headers_path);
+        }
+    }
+
+    build
+        .file("src/inline.c")
+        .define("CP_USE_CGTYPES", "0")
+        .define("CP_USE_DOUBLES", CP_USE_DOUBLES)
+        .warnings(false)
+        // allows a Rust panic raised by the message handler to unwind through the functions that call into chipmunk
+        .flag_if_supported("-fexceptions")
+        .compile("chipmunk_inline");
 }
 
 #[cfg(not(feature = "bindgen"))]
 fn copy_pregenerated_bindings() {
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     let crate_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let bindings_name = if cfg!(feature = "f32") {
+        "chipmunk_bindings_f32.rs"
+    } else {
+        "chipmunk_bindings.rs"
+    };
+    println!("cargo:rerun-if-changed={}", bindings_name);
     fs::copy(
-        crate_path.join("chipmunk_bindings.rs"),
+        crate_path.join(bindings_name),
         out_path.join("chipmunk_bindings.rs"),
     )
     .expect("Couldn't find pregenerated bindings!");
@@ -165,13 +423,16 @@ fn generate_bindings(target: &str, host: &str, headers_paths: &[String]) {
         }
     }
 
+    println!("cargo:rerun-if-changed=wrapper.h");
     let bindings = bindings
         .header("wrapper.h")
         .clang_arg("-DCP_USE_CGTYPES=0")
+        .clang_arg(format!("-DCP_USE_DOUBLES={}", CP_USE_DOUBLES))
         .clang_arg("-fno-blocks")
         .whitelist_type("cp.*")
         .whitelist_function("cp.*")
         .whitelist_var("CP_.*")
+        .whitelist_var("cpVersionString")
         .whitelist_function("CP_.*")
         .derive_debug(false)
         .generate()
@@ -179,9 +440,12 @@ fn generate_bindings(target: &str, host: &str, headers_paths: &[String]) {
 
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
 
-    bindings
-        .write_to_file(out_path.join("chipmunk_bindings.rs"))
-        .expect("Couldn't write bindings!");
+    // the message handler may panic inside any chipmunk function, which is only defined behavior
+    // when the functions are imported with an ABI that allows unwinding, like the pregenerated bindings
+    let bindings = bindings
+        .to_string()
+        .replace("extern \"C\" {", "extern \"C-unwind\" {");
+    fs::write(out_path.join("chipmunk_bindings.rs"), bindings).expect("Couldn't write bindings!");
 }
 
 fn get_os_from_triple(triple: &str) -> Option<&str> {
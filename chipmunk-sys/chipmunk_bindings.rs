@@ -1,5 +1,36 @@
 /* automatically generated by rust-bindgen */
 
+#[repr(C)]
+#[derive(Default)]
+pub struct __IncompleteArrayField<T>(::core::marker::PhantomData<T>, [T; 0]);
+impl<T> __IncompleteArrayField<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        __IncompleteArrayField(::core::marker::PhantomData, [])
+    }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self as *const _ as *const T
+    }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut _ as *mut T
+    }
+    #[inline]
+    pub unsafe fn as_slice(&self, len: usize) -> &[T] {
+        ::core::slice::from_raw_parts(self.as_ptr(), len)
+    }
+    #[inline]
+    pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
+        ::core::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
+    }
+}
+impl<T> ::core::fmt::Debug for __IncompleteArrayField<T> {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        fmt.write_str("__IncompleteArrayField")
+    }
+}
+
 pub const CP_USE_DOUBLES: u32 = 1;
 pub const CP_BUFFER_BYTES: u32 = 32768;
 pub const CP_MAX_CONTACTS_PER_ARBITER: u32 = 2;
@@ -7,7 +38,11 @@ pub const CP_VERSION_MAJOR: u32 = 7;
 pub const CP_VERSION_MINOR: u32 = 0;
 pub const CP_VERSION_RELEASE: u32 = 3;
 pub const CP_POLY_SHAPE_INLINE_ALLOC: u32 = 6;
-extern "C" {
+extern "C-unwind" {
+    #[doc = " Version string."]
+    pub static mut cpVersionString: *const libc::c_char;
+}
+extern "C-unwind" {
     pub fn cpMessage(
         condition: *const libc::c_char,
         file: *const libc::c_char,
@@ -356,11 +391,11 @@ fn bindgen_test_layout_cpSpatialIndex() {
 pub struct cpSpaceHash {
     _unused: [u8; 0],
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a spatial hash."]
     pub fn cpSpaceHashAlloc() -> *mut cpSpaceHash;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a spatial hash."]
     pub fn cpSpaceHashInit(
         hash: *mut cpSpaceHash,
@@ -370,7 +405,7 @@ extern "C" {
         staticIndex: *mut cpSpatialIndex,
     ) -> *mut cpSpatialIndex;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a spatial hash."]
     pub fn cpSpaceHashNew(
         celldim: cpFloat,
@@ -379,7 +414,7 @@ extern "C" {
         staticIndex: *mut cpSpatialIndex,
     ) -> *mut cpSpatialIndex;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Change the cell dimensions and table size of the spatial hash to tune it."]
     #[doc = " The cell dimensions should roughly match the average size of your objects"]
     #[doc = " and the table size should be ~10 larger than the number of objects inserted."]
@@ -391,11 +426,11 @@ extern "C" {
 pub struct cpBBTree {
     _unused: [u8; 0],
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a bounding box tree."]
     pub fn cpBBTreeAlloc() -> *mut cpBBTree;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a bounding box tree."]
     pub fn cpBBTreeInit(
         tree: *mut cpBBTree,
@@ -403,14 +438,14 @@ extern "C" {
         staticIndex: *mut cpSpatialIndex,
     ) -> *mut cpSpatialIndex;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a bounding box tree."]
     pub fn cpBBTreeNew(
         bbfunc: cpSpatialIndexBBFunc,
         staticIndex: *mut cpSpatialIndex,
     ) -> *mut cpSpatialIndex;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a static top down optimization of the tree."]
     pub fn cpBBTreeOptimize(index: *mut cpSpatialIndex);
 }
@@ -418,7 +453,7 @@ extern "C" {
 #[doc = " This function should return an estimate for the object's velocity."]
 pub type cpBBTreeVelocityFunc =
     ::core::option::Option<unsafe extern "C" fn(obj: *mut libc::c_void) -> cpVect>;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the velocity function for the bounding box tree to enable temporal coherence."]
     pub fn cpBBTreeSetVelocityFunc(index: *mut cpSpatialIndex, func: cpBBTreeVelocityFunc);
 }
@@ -427,11 +462,11 @@ extern "C" {
 pub struct cpSweep1D {
     _unused: [u8; 0],
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a 1D sort and sweep broadphase."]
     pub fn cpSweep1DAlloc() -> *mut cpSweep1D;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a 1D sort and sweep broadphase."]
     pub fn cpSweep1DInit(
         sweep: *mut cpSweep1D,
@@ -439,7 +474,7 @@ extern "C" {
         staticIndex: *mut cpSpatialIndex,
     ) -> *mut cpSpatialIndex;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a 1D sort and sweep broadphase."]
     pub fn cpSweep1DNew(
         bbfunc: cpSpatialIndexBBFunc,
@@ -646,11 +681,11 @@ fn bindgen_test_layout_cpSpatialIndexClass() {
         )
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy and free a spatial index."]
     pub fn cpSpatialIndexFree(index: *mut cpSpatialIndex);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Collide the objects in @c dynamicIndex against the objects in @c staticIndex using the query callback function."]
     pub fn cpSpatialIndexCollideStatic(
         dynamicIndex: *mut cpSpatialIndex,
@@ -659,59 +694,59 @@ extern "C" {
         data: *mut libc::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the restitution (elasticity) that will be applied to the pair of colliding objects."]
     pub fn cpArbiterGetRestitution(arb: *const cpArbiter) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Override the restitution (elasticity) that will be applied to the pair of colliding objects."]
     pub fn cpArbiterSetRestitution(arb: *mut cpArbiter, restitution: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the friction coefficient that will be applied to the pair of colliding objects."]
     pub fn cpArbiterGetFriction(arb: *const cpArbiter) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Override the friction coefficient that will be applied to the pair of colliding objects."]
     pub fn cpArbiterSetFriction(arb: *mut cpArbiter, friction: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpArbiterGetSurfaceVelocity(arb: *mut cpArbiter) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpArbiterSetSurfaceVelocity(arb: *mut cpArbiter, vr: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the user data pointer associated with this pair of colliding objects."]
     pub fn cpArbiterGetUserData(arb: *const cpArbiter) -> cpDataPointer;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set a user data point associated with this pair of colliding objects."]
     #[doc = " If you need to perform any cleanup for this pointer, you must do it yourself, in the separate callback for instance."]
     pub fn cpArbiterSetUserData(arb: *mut cpArbiter, userData: cpDataPointer);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the total impulse including the friction that was applied by this arbiter."]
     #[doc = " This function should only be called from a post-solve, post-step or cpBodyEachArbiter callback."]
     pub fn cpArbiterTotalImpulse(arb: *const cpArbiter) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the amount of energy lost in a collision including static, but not dynamic friction."]
     #[doc = " This function should only be called from a post-solve, post-step or cpBodyEachArbiter callback."]
     pub fn cpArbiterTotalKE(arb: *const cpArbiter) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Mark a collision pair to be ignored until the two objects separate."]
     #[doc = " Pre-solve and post-solve callbacks will not be called, but the separate callback will be called."]
     pub fn cpArbiterIgnore(arb: *mut cpArbiter) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Return the colliding shapes involved for this arbiter."]
     #[doc = " The order of their cpSpace.collision_type values will match"]
     #[doc = " the order set when the collision handler was registered."]
     pub fn cpArbiterGetShapes(arb: *const cpArbiter, a: *mut *mut cpShape, b: *mut *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Return the colliding bodies involved for this arbiter."]
     #[doc = " The order of the cpSpace.collision_type the bodies are associated with values will match"]
     #[doc = " the order set when the collision handler was registered."]
@@ -832,76 +867,76 @@ fn bindgen_test_layout_cpContactPointSet() {
         )
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Return a contact set from an arbiter."]
     pub fn cpArbiterGetContactPointSet(arb: *const cpArbiter) -> cpContactPointSet;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Replace the contact point set for an arbiter."]
     #[doc = " This can be a very powerful feature, but use it with caution!"]
     pub fn cpArbiterSetContactPointSet(arb: *mut cpArbiter, set: *mut cpContactPointSet);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Returns true if this is the first step a pair of objects started colliding."]
     pub fn cpArbiterIsFirstContact(arb: *const cpArbiter) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Returns true if the separate callback is due to a shape being removed from the space."]
     pub fn cpArbiterIsRemoval(arb: *const cpArbiter) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the number of contact points for this arbiter."]
     pub fn cpArbiterGetCount(arb: *const cpArbiter) -> libc::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the normal of the collision."]
     pub fn cpArbiterGetNormal(arb: *const cpArbiter) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the position of the @c ith contact point on the surface of the first shape."]
     pub fn cpArbiterGetPointA(arb: *const cpArbiter, i: libc::c_int) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the position of the @c ith contact point on the surface of the second shape."]
     pub fn cpArbiterGetPointB(arb: *const cpArbiter, i: libc::c_int) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the depth of the @c ith contact point."]
     pub fn cpArbiterGetDepth(arb: *const cpArbiter, i: libc::c_int) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the first collision type, you must call this function explicitly."]
     #[doc = " You must decide how to handle the wildcard's return value since it may disagree with the other wildcard handler's return value or your own."]
     pub fn cpArbiterCallWildcardBeginA(arb: *mut cpArbiter, space: *mut cpSpace) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the second collision type, you must call this function explicitly."]
     #[doc = " You must decide how to handle the wildcard's return value since it may disagree with the other wildcard handler's return value or your own."]
     pub fn cpArbiterCallWildcardBeginB(arb: *mut cpArbiter, space: *mut cpSpace) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the first collision type, you must call this function explicitly."]
     #[doc = " You must decide how to handle the wildcard's return value since it may disagree with the other wildcard handler's return value or your own."]
     pub fn cpArbiterCallWildcardPreSolveA(arb: *mut cpArbiter, space: *mut cpSpace) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the second collision type, you must call this function explicitly."]
     #[doc = " You must decide how to handle the wildcard's return value since it may disagree with the other wildcard handler's return value or your own."]
     pub fn cpArbiterCallWildcardPreSolveB(arb: *mut cpArbiter, space: *mut cpSpace) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the first collision type, you must call this function explicitly."]
     pub fn cpArbiterCallWildcardPostSolveA(arb: *mut cpArbiter, space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the second collision type, you must call this function explicitly."]
     pub fn cpArbiterCallWildcardPostSolveB(arb: *mut cpArbiter, space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the first collision type, you must call this function explicitly."]
     pub fn cpArbiterCallWildcardSeparateA(arb: *mut cpArbiter, space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " If you want a custom callback to invoke the wildcard callback for the second collision type, you must call this function explicitly."]
     pub fn cpArbiterCallWildcardSeparateB(arb: *mut cpArbiter, space: *mut cpSpace);
 }
@@ -933,200 +968,200 @@ pub type cpBodyVelocityFunc = ::core::option::Option<
 #[doc = " Rigid body position update function type."]
 pub type cpBodyPositionFunc =
     ::core::option::Option<unsafe extern "C" fn(body: *mut cpBody, dt: cpFloat)>;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a cpBody."]
     pub fn cpBodyAlloc() -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a cpBody."]
     pub fn cpBodyInit(body: *mut cpBody, mass: cpFloat, moment: cpFloat) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a cpBody."]
     pub fn cpBodyNew(mass: cpFloat, moment: cpFloat) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a cpBody, and set it as a kinematic body."]
     pub fn cpBodyNewKinematic() -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a cpBody, and set it as a static body."]
     pub fn cpBodyNewStatic() -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy a cpBody."]
     pub fn cpBodyDestroy(body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy and free a cpBody."]
     pub fn cpBodyFree(body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Wake up a sleeping or idle body."]
     pub fn cpBodyActivate(body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Wake up any sleeping or idle bodies touching a static body."]
     pub fn cpBodyActivateStatic(body: *mut cpBody, filter: *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Force a body to fall asleep immediately."]
     pub fn cpBodySleep(body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Force a body to fall asleep immediately along with other bodies in a group."]
     pub fn cpBodySleepWithGroup(body: *mut cpBody, group: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Returns true if the body is sleeping."]
     pub fn cpBodyIsSleeping(body: *const cpBody) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the type of the body."]
     pub fn cpBodyGetType(body: *mut cpBody) -> cpBodyType;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the type of the body."]
     pub fn cpBodySetType(body: *mut cpBody, type_: cpBodyType);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the space this body is added to."]
     pub fn cpBodyGetSpace(body: *const cpBody) -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the mass of the body."]
     pub fn cpBodyGetMass(body: *const cpBody) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the mass of the body."]
     pub fn cpBodySetMass(body: *mut cpBody, m: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the moment of inertia of the body."]
     pub fn cpBodyGetMoment(body: *const cpBody) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the moment of inertia of the body."]
     pub fn cpBodySetMoment(body: *mut cpBody, i: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the position of a body."]
     pub fn cpBodyGetPosition(body: *const cpBody) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the position of the body."]
     pub fn cpBodySetPosition(body: *mut cpBody, pos: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the offset of the center of gravity in body local coordinates."]
     pub fn cpBodyGetCenterOfGravity(body: *const cpBody) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the offset of the center of gravity in body local coordinates."]
     pub fn cpBodySetCenterOfGravity(body: *mut cpBody, cog: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the velocity of the body."]
     pub fn cpBodyGetVelocity(body: *const cpBody) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the velocity of the body."]
     pub fn cpBodySetVelocity(body: *mut cpBody, velocity: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the force applied to the body for the next time step."]
     pub fn cpBodyGetForce(body: *const cpBody) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the force applied to the body for the next time step."]
     pub fn cpBodySetForce(body: *mut cpBody, force: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the angle of the body."]
     pub fn cpBodyGetAngle(body: *const cpBody) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the angle of a body."]
     pub fn cpBodySetAngle(body: *mut cpBody, a: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the angular velocity of the body."]
     pub fn cpBodyGetAngularVelocity(body: *const cpBody) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the angular velocity of the body."]
     pub fn cpBodySetAngularVelocity(body: *mut cpBody, angularVelocity: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the torque applied to the body for the next time step."]
     pub fn cpBodyGetTorque(body: *const cpBody) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the torque applied to the body for the next time step."]
     pub fn cpBodySetTorque(body: *mut cpBody, torque: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the rotation vector of the body. (The x basis vector of it's transform.)"]
     pub fn cpBodyGetRotation(body: *const cpBody) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the user data pointer assigned to the body."]
     pub fn cpBodyGetUserData(body: *const cpBody) -> cpDataPointer;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the user data pointer assigned to the body."]
     pub fn cpBodySetUserData(body: *mut cpBody, userData: cpDataPointer);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the callback used to update a body's velocity."]
     pub fn cpBodySetVelocityUpdateFunc(body: *mut cpBody, velocityFunc: cpBodyVelocityFunc);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the callback used to update a body's position."]
     #[doc = " NOTE: It's not generally recommended to override this unless you call the default position update function."]
     pub fn cpBodySetPositionUpdateFunc(body: *mut cpBody, positionFunc: cpBodyPositionFunc);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Default velocity integration function.."]
     pub fn cpBodyUpdateVelocity(body: *mut cpBody, gravity: cpVect, damping: cpFloat, dt: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Default position integration function."]
     pub fn cpBodyUpdatePosition(body: *mut cpBody, dt: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Convert body relative/local coordinates to absolute/world coordinates."]
     pub fn cpBodyLocalToWorld(body: *const cpBody, point: cpVect) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Convert body absolute/world coordinates to  relative/local coordinates."]
     pub fn cpBodyWorldToLocal(body: *const cpBody, point: cpVect) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Apply a force to a body. Both the force and point are expressed in world coordinates."]
     pub fn cpBodyApplyForceAtWorldPoint(body: *mut cpBody, force: cpVect, point: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Apply a force to a body. Both the force and point are expressed in body local coordinates."]
     pub fn cpBodyApplyForceAtLocalPoint(body: *mut cpBody, force: cpVect, point: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Apply an impulse to a body. Both the impulse and point are expressed in world coordinates."]
     pub fn cpBodyApplyImpulseAtWorldPoint(body: *mut cpBody, impulse: cpVect, point: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Apply an impulse to a body. Both the impulse and point are expressed in body local coordinates."]
     pub fn cpBodyApplyImpulseAtLocalPoint(body: *mut cpBody, impulse: cpVect, point: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the velocity on a body (in world units) at a point on the body in world coordinates."]
     pub fn cpBodyGetVelocityAtWorldPoint(body: *const cpBody, point: cpVect) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the velocity on a body (in world units) at a point on the body in local coordinates."]
     pub fn cpBodyGetVelocityAtLocalPoint(body: *const cpBody, point: cpVect) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the amount of kinetic energy contained by the body."]
     pub fn cpBodyKineticEnergy(body: *const cpBody) -> cpFloat;
 }
@@ -1134,7 +1169,7 @@ extern "C" {
 pub type cpBodyShapeIteratorFunc = ::core::option::Option<
     unsafe extern "C" fn(body: *mut cpBody, shape: *mut cpShape, data: *mut libc::c_void),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func once for each shape attached to @c body and added to the space."]
     pub fn cpBodyEachShape(
         body: *mut cpBody,
@@ -1146,7 +1181,7 @@ extern "C" {
 pub type cpBodyConstraintIteratorFunc = ::core::option::Option<
     unsafe extern "C" fn(body: *mut cpBody, constraint: *mut cpConstraint, data: *mut libc::c_void),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func once for each constraint attached to @c body and added to the space."]
     pub fn cpBodyEachConstraint(
         body: *mut cpBody,
@@ -1158,7 +1193,7 @@ extern "C" {
 pub type cpBodyArbiterIteratorFunc = ::core::option::Option<
     unsafe extern "C" fn(body: *mut cpBody, arbiter: *mut cpArbiter, data: *mut libc::c_void),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func once for each arbiter that is currently active on the body."]
     pub fn cpBodyEachArbiter(
         body: *mut cpBody,
@@ -1356,29 +1391,29 @@ fn bindgen_test_layout_cpShapeFilter() {
         )
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub static CP_SHAPE_FILTER_ALL: cpShapeFilter;
 }
-extern "C" {
+extern "C-unwind" {
     pub static CP_SHAPE_FILTER_NONE: cpShapeFilter;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy a shape."]
     pub fn cpShapeDestroy(shape: *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy and Free a shape."]
     pub fn cpShapeFree(shape: *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Update, cache and return the bounding box of a shape based on the body it's attached to."]
     pub fn cpShapeCacheBB(shape: *mut cpShape) -> cpBB;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Update, cache and return the bounding box of a shape with an explicit transformation."]
     pub fn cpShapeUpdate(shape: *mut cpShape, transform: cpTransform) -> cpBB;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a nearest point query. It finds the closest point on the surface of shape to a specific point."]
     #[doc = " The value returned is the distance between the points. A negative distance means the point is inside the shape."]
     pub fn cpShapePointQuery(
@@ -1387,7 +1422,7 @@ extern "C" {
         out: *mut cpPointQueryInfo,
     ) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a segment query against a shape. @c info must be a pointer to a valid cpSegmentQueryInfo structure."]
     pub fn cpShapeSegmentQuery(
         shape: *const cpShape,
@@ -1397,116 +1432,116 @@ extern "C" {
         info: *mut cpSegmentQueryInfo,
     ) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Return contact information about two shapes."]
     pub fn cpShapesCollide(a: *const cpShape, b: *const cpShape) -> cpContactPointSet;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " The cpSpace this body is added to."]
     pub fn cpShapeGetSpace(shape: *const cpShape) -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " The cpBody this shape is connected to."]
     pub fn cpShapeGetBody(shape: *const cpShape) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the cpBody this shape is connected to."]
     #[doc = " Can only be used if the shape is not currently added to a space."]
     pub fn cpShapeSetBody(shape: *mut cpShape, body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the mass of the shape if you are having Chipmunk calculate mass properties for you."]
     pub fn cpShapeGetMass(shape: *mut cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the mass of this shape to have Chipmunk calculate mass properties for you."]
     pub fn cpShapeSetMass(shape: *mut cpShape, mass: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the density of the shape if you are having Chipmunk calculate mass properties for you."]
     pub fn cpShapeGetDensity(shape: *mut cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the density  of this shape to have Chipmunk calculate mass properties for you."]
     pub fn cpShapeSetDensity(shape: *mut cpShape, density: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the calculated moment of inertia for this shape."]
     pub fn cpShapeGetMoment(shape: *mut cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the calculated area of this shape."]
     pub fn cpShapeGetArea(shape: *mut cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the centroid of this shape."]
     pub fn cpShapeGetCenterOfGravity(shape: *mut cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the bounding box that contains the shape given it's current position and angle."]
     pub fn cpShapeGetBB(shape: *const cpShape) -> cpBB;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get if the shape is set to be a sensor or not."]
     pub fn cpShapeGetSensor(shape: *const cpShape) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set if the shape is a sensor or not."]
     pub fn cpShapeSetSensor(shape: *mut cpShape, sensor: cpBool);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the elasticity of this shape."]
     pub fn cpShapeGetElasticity(shape: *const cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the elasticity of this shape."]
     pub fn cpShapeSetElasticity(shape: *mut cpShape, elasticity: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the friction of this shape."]
     pub fn cpShapeGetFriction(shape: *const cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the friction of this shape."]
     pub fn cpShapeSetFriction(shape: *mut cpShape, friction: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the surface velocity of this shape."]
     pub fn cpShapeGetSurfaceVelocity(shape: *const cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the surface velocity of this shape."]
     pub fn cpShapeSetSurfaceVelocity(shape: *mut cpShape, surfaceVelocity: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the user definable data pointer of this shape."]
     pub fn cpShapeGetUserData(shape: *const cpShape) -> cpDataPointer;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the user definable data pointer of this shape."]
     pub fn cpShapeSetUserData(shape: *mut cpShape, userData: cpDataPointer);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the collision type of this shape."]
     pub fn cpShapeGetCollisionType(shape: *const cpShape) -> cpCollisionType;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the collision type of this shape."]
     pub fn cpShapeSetCollisionType(shape: *mut cpShape, collisionType: cpCollisionType);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the collision filtering parameters of this shape."]
     pub fn cpShapeGetFilter(shape: *const cpShape) -> cpShapeFilter;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the collision filtering parameters of this shape."]
     pub fn cpShapeSetFilter(shape: *mut cpShape, filter: cpShapeFilter);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a circle shape."]
     pub fn cpCircleShapeAlloc() -> *mut cpCircleShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a circle shape."]
     pub fn cpCircleShapeInit(
         circle: *mut cpCircleShape,
@@ -1515,23 +1550,23 @@ extern "C" {
         offset: cpVect,
     ) -> *mut cpCircleShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a circle shape."]
     pub fn cpCircleShapeNew(body: *mut cpBody, radius: cpFloat, offset: cpVect) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the offset of a circle shape."]
     pub fn cpCircleShapeGetOffset(shape: *const cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the radius of a circle shape."]
     pub fn cpCircleShapeGetRadius(shape: *const cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a segment shape."]
     pub fn cpSegmentShapeAlloc() -> *mut cpSegmentShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a segment shape."]
     pub fn cpSegmentShapeInit(
         seg: *mut cpSegmentShape,
@@ -1541,7 +1576,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpSegmentShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a segment shape."]
     pub fn cpSegmentShapeNew(
         body: *mut cpBody,
@@ -1550,31 +1585,31 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Let Chipmunk know about the geometry of adjacent segments to avoid colliding with endcaps."]
     pub fn cpSegmentShapeSetNeighbors(shape: *mut cpShape, prev: cpVect, next: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the first endpoint of a segment shape."]
     pub fn cpSegmentShapeGetA(shape: *const cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the second endpoint of a segment shape."]
     pub fn cpSegmentShapeGetB(shape: *const cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the normal of a segment shape."]
     pub fn cpSegmentShapeGetNormal(shape: *const cpShape) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the first endpoint of a segment shape."]
     pub fn cpSegmentShapeGetRadius(shape: *const cpShape) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a polygon shape."]
     pub fn cpPolyShapeAlloc() -> *mut cpPolyShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a polygon shape with rounded corners."]
     #[doc = " A convex hull will be created from the vertexes."]
     pub fn cpPolyShapeInit(
@@ -1586,7 +1621,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpPolyShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a polygon shape with rounded corners."]
     #[doc = " The vertexes must be convex with a counter-clockwise winding."]
     pub fn cpPolyShapeInitRaw(
@@ -1597,7 +1632,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpPolyShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a polygon shape with rounded corners."]
     #[doc = " A convex hull will be created from the vertexes."]
     pub fn cpPolyShapeNew(
@@ -1608,7 +1643,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a polygon shape with rounded corners."]
     #[doc = " The vertexes must be convex with a counter-clockwise winding."]
     pub fn cpPolyShapeNewRaw(
@@ -1618,7 +1653,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a box shaped polygon shape with rounded corners."]
     pub fn cpBoxShapeInit(
         poly: *mut cpPolyShape,
@@ -1628,7 +1663,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpPolyShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize an offset box shaped polygon shape with rounded corners."]
     pub fn cpBoxShapeInit2(
         poly: *mut cpPolyShape,
@@ -1637,7 +1672,7 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpPolyShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a box shaped polygon shape."]
     pub fn cpBoxShapeNew(
         body: *mut cpBody,
@@ -1646,19 +1681,19 @@ extern "C" {
         radius: cpFloat,
     ) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize an offset box shaped polygon shape."]
     pub fn cpBoxShapeNew2(body: *mut cpBody, box_: cpBB, radius: cpFloat) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the number of verts in a polygon shape."]
     pub fn cpPolyShapeGetCount(shape: *const cpShape) -> libc::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the @c ith vertex of a polygon shape."]
     pub fn cpPolyShapeGetVert(shape: *const cpShape, index: libc::c_int) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the radius of a polygon shape."]
     pub fn cpPolyShapeGetRadius(shape: *const cpShape) -> cpFloat;
 }
@@ -1670,106 +1705,106 @@ pub type cpConstraintPreSolveFunc = ::core::option::Option<
 pub type cpConstraintPostSolveFunc = ::core::option::Option<
     unsafe extern "C" fn(constraint: *mut cpConstraint, space: *mut cpSpace),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy a constraint."]
     pub fn cpConstraintDestroy(constraint: *mut cpConstraint);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy and free a constraint."]
     pub fn cpConstraintFree(constraint: *mut cpConstraint);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the cpSpace this constraint is added to."]
     pub fn cpConstraintGetSpace(constraint: *const cpConstraint) -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the first body the constraint is attached to."]
     pub fn cpConstraintGetBodyA(constraint: *const cpConstraint) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the second body the constraint is attached to."]
     pub fn cpConstraintGetBodyB(constraint: *const cpConstraint) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the maximum force that this constraint is allowed to use."]
     pub fn cpConstraintGetMaxForce(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the maximum force that this constraint is allowed to use. (defaults to INFINITY)"]
     pub fn cpConstraintSetMaxForce(constraint: *mut cpConstraint, maxForce: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get rate at which joint error is corrected."]
     pub fn cpConstraintGetErrorBias(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set rate at which joint error is corrected."]
     #[doc = " Defaults to pow(1.0 - 0.1, 60.0) meaning that it will"]
     #[doc = " correct 10% of the error every 1/60th of a second."]
     pub fn cpConstraintSetErrorBias(constraint: *mut cpConstraint, errorBias: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the maximum rate at which joint error is corrected."]
     pub fn cpConstraintGetMaxBias(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the maximum rate at which joint error is corrected. (defaults to INFINITY)"]
     pub fn cpConstraintSetMaxBias(constraint: *mut cpConstraint, maxBias: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get if the two bodies connected by the constraint are allowed to collide or not."]
     pub fn cpConstraintGetCollideBodies(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set if the two bodies connected by the constraint are allowed to collide or not. (defaults to cpFalse)"]
     pub fn cpConstraintSetCollideBodies(constraint: *mut cpConstraint, collideBodies: cpBool);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the pre-solve function that is called before the solver runs."]
     pub fn cpConstraintGetPreSolveFunc(constraint: *const cpConstraint)
         -> cpConstraintPreSolveFunc;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the pre-solve function that is called before the solver runs."]
     pub fn cpConstraintSetPreSolveFunc(
         constraint: *mut cpConstraint,
         preSolveFunc: cpConstraintPreSolveFunc,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the post-solve function that is called before the solver runs."]
     pub fn cpConstraintGetPostSolveFunc(
         constraint: *const cpConstraint,
     ) -> cpConstraintPostSolveFunc;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the post-solve function that is called before the solver runs."]
     pub fn cpConstraintSetPostSolveFunc(
         constraint: *mut cpConstraint,
         postSolveFunc: cpConstraintPostSolveFunc,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the user definable data pointer for this constraint"]
     pub fn cpConstraintGetUserData(constraint: *const cpConstraint) -> cpDataPointer;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the user definable data pointer for this constraint"]
     pub fn cpConstraintSetUserData(constraint: *mut cpConstraint, userData: cpDataPointer);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the last impulse applied by this constraint."]
     pub fn cpConstraintGetImpulse(constraint: *mut cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a pin joint."]
     pub fn cpConstraintIsPinJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a pin joint."]
     pub fn cpPinJointAlloc() -> *mut cpPinJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a pin joint."]
     pub fn cpPinJointInit(
         joint: *mut cpPinJoint,
@@ -1779,7 +1814,7 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpPinJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a pin joint."]
     pub fn cpPinJointNew(
         a: *mut cpBody,
@@ -1788,39 +1823,39 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the first anchor relative to the first body."]
     pub fn cpPinJointGetAnchorA(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the first anchor relative to the first body."]
     pub fn cpPinJointSetAnchorA(constraint: *mut cpConstraint, anchorA: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the second anchor relative to the second body."]
     pub fn cpPinJointGetAnchorB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the second anchor relative to the second body."]
     pub fn cpPinJointSetAnchorB(constraint: *mut cpConstraint, anchorB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the distance the joint will maintain between the two anchors."]
     pub fn cpPinJointGetDist(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the distance the joint will maintain between the two anchors."]
     pub fn cpPinJointSetDist(constraint: *mut cpConstraint, dist: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a slide joint."]
     pub fn cpConstraintIsSlideJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a slide joint."]
     pub fn cpSlideJointAlloc() -> *mut cpSlideJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a slide joint."]
     pub fn cpSlideJointInit(
         joint: *mut cpSlideJoint,
@@ -1832,7 +1867,7 @@ extern "C" {
         max: cpFloat,
     ) -> *mut cpSlideJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a slide joint."]
     pub fn cpSlideJointNew(
         a: *mut cpBody,
@@ -1843,47 +1878,47 @@ extern "C" {
         max: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the first anchor relative to the first body."]
     pub fn cpSlideJointGetAnchorA(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the first anchor relative to the first body."]
     pub fn cpSlideJointSetAnchorA(constraint: *mut cpConstraint, anchorA: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the second anchor relative to the second body."]
     pub fn cpSlideJointGetAnchorB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the second anchor relative to the second body."]
     pub fn cpSlideJointSetAnchorB(constraint: *mut cpConstraint, anchorB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the minimum distance the joint will maintain between the two anchors."]
     pub fn cpSlideJointGetMin(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the minimum distance the joint will maintain between the two anchors."]
     pub fn cpSlideJointSetMin(constraint: *mut cpConstraint, min: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the maximum distance the joint will maintain between the two anchors."]
     pub fn cpSlideJointGetMax(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the maximum distance the joint will maintain between the two anchors."]
     pub fn cpSlideJointSetMax(constraint: *mut cpConstraint, max: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a slide joint."]
     pub fn cpConstraintIsPivotJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a pivot joint"]
     pub fn cpPivotJointAlloc() -> *mut cpPivotJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a pivot joint."]
     pub fn cpPivotJointInit(
         joint: *mut cpPivotJoint,
@@ -1893,11 +1928,11 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpPivotJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a pivot joint."]
     pub fn cpPivotJointNew(a: *mut cpBody, b: *mut cpBody, pivot: cpVect) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a pivot joint with specific anchors."]
     pub fn cpPivotJointNew2(
         a: *mut cpBody,
@@ -1906,31 +1941,31 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the first anchor relative to the first body."]
     pub fn cpPivotJointGetAnchorA(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the first anchor relative to the first body."]
     pub fn cpPivotJointSetAnchorA(constraint: *mut cpConstraint, anchorA: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the second anchor relative to the second body."]
     pub fn cpPivotJointGetAnchorB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the second anchor relative to the second body."]
     pub fn cpPivotJointSetAnchorB(constraint: *mut cpConstraint, anchorB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a slide joint."]
     pub fn cpConstraintIsGrooveJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a groove joint."]
     pub fn cpGrooveJointAlloc() -> *mut cpGrooveJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a groove joint."]
     pub fn cpGrooveJointInit(
         joint: *mut cpGrooveJoint,
@@ -1941,7 +1976,7 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpGrooveJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a groove joint."]
     pub fn cpGrooveJointNew(
         a: *mut cpBody,
@@ -1951,31 +1986,31 @@ extern "C" {
         anchorB: cpVect,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the first endpoint of the groove relative to the first body."]
     pub fn cpGrooveJointGetGrooveA(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the first endpoint of the groove relative to the first body."]
     pub fn cpGrooveJointSetGrooveA(constraint: *mut cpConstraint, grooveA: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the first endpoint of the groove relative to the first body."]
     pub fn cpGrooveJointGetGrooveB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the first endpoint of the groove relative to the first body."]
     pub fn cpGrooveJointSetGrooveB(constraint: *mut cpConstraint, grooveB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the second anchor relative to the second body."]
     pub fn cpGrooveJointGetAnchorB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the second anchor relative to the second body."]
     pub fn cpGrooveJointSetAnchorB(constraint: *mut cpConstraint, anchorB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a slide joint."]
     pub fn cpConstraintIsDampedSpring(constraint: *const cpConstraint) -> cpBool;
 }
@@ -1983,11 +2018,11 @@ extern "C" {
 pub type cpDampedSpringForceFunc = ::core::option::Option<
     unsafe extern "C" fn(spring: *mut cpConstraint, dist: cpFloat) -> cpFloat,
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a damped spring."]
     pub fn cpDampedSpringAlloc() -> *mut cpDampedSpring;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a damped spring."]
     pub fn cpDampedSpringInit(
         joint: *mut cpDampedSpring,
@@ -2000,7 +2035,7 @@ extern "C" {
         damping: cpFloat,
     ) -> *mut cpDampedSpring;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a damped spring."]
     pub fn cpDampedSpringNew(
         a: *mut cpBody,
@@ -2012,60 +2047,60 @@ extern "C" {
         damping: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the first anchor relative to the first body."]
     pub fn cpDampedSpringGetAnchorA(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the first anchor relative to the first body."]
     pub fn cpDampedSpringSetAnchorA(constraint: *mut cpConstraint, anchorA: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the location of the second anchor relative to the second body."]
     pub fn cpDampedSpringGetAnchorB(constraint: *const cpConstraint) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the location of the second anchor relative to the second body."]
     pub fn cpDampedSpringSetAnchorB(constraint: *mut cpConstraint, anchorB: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the rest length of the spring."]
     pub fn cpDampedSpringGetRestLength(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the rest length of the spring."]
     pub fn cpDampedSpringSetRestLength(constraint: *mut cpConstraint, restLength: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the stiffness of the spring in force/distance."]
     pub fn cpDampedSpringGetStiffness(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the stiffness of the spring in force/distance."]
     pub fn cpDampedSpringSetStiffness(constraint: *mut cpConstraint, stiffness: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the damping of the spring."]
     pub fn cpDampedSpringGetDamping(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the damping of the spring."]
     pub fn cpDampedSpringSetDamping(constraint: *mut cpConstraint, damping: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the damping of the spring."]
     pub fn cpDampedSpringGetSpringForceFunc(
         constraint: *const cpConstraint,
     ) -> cpDampedSpringForceFunc;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the damping of the spring."]
     pub fn cpDampedSpringSetSpringForceFunc(
         constraint: *mut cpConstraint,
         springForceFunc: cpDampedSpringForceFunc,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a damped rotary springs."]
     pub fn cpConstraintIsDampedRotarySpring(constraint: *const cpConstraint) -> cpBool;
 }
@@ -2073,11 +2108,11 @@ extern "C" {
 pub type cpDampedRotarySpringTorqueFunc = ::core::option::Option<
     unsafe extern "C" fn(spring: *mut cpConstraint, relativeAngle: cpFloat) -> cpFloat,
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a damped rotary spring."]
     pub fn cpDampedRotarySpringAlloc() -> *mut cpDampedRotarySpring;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a damped rotary spring."]
     pub fn cpDampedRotarySpringInit(
         joint: *mut cpDampedRotarySpring,
@@ -2088,7 +2123,7 @@ extern "C" {
         damping: cpFloat,
     ) -> *mut cpDampedRotarySpring;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a damped rotary spring."]
     pub fn cpDampedRotarySpringNew(
         a: *mut cpBody,
@@ -2098,52 +2133,52 @@ extern "C" {
         damping: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the rest length of the spring."]
     pub fn cpDampedRotarySpringGetRestAngle(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the rest length of the spring."]
     pub fn cpDampedRotarySpringSetRestAngle(constraint: *mut cpConstraint, restAngle: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the stiffness of the spring in force/distance."]
     pub fn cpDampedRotarySpringGetStiffness(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the stiffness of the spring in force/distance."]
     pub fn cpDampedRotarySpringSetStiffness(constraint: *mut cpConstraint, stiffness: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the damping of the spring."]
     pub fn cpDampedRotarySpringGetDamping(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the damping of the spring."]
     pub fn cpDampedRotarySpringSetDamping(constraint: *mut cpConstraint, damping: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the damping of the spring."]
     pub fn cpDampedRotarySpringGetSpringTorqueFunc(
         constraint: *const cpConstraint,
     ) -> cpDampedRotarySpringTorqueFunc;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the damping of the spring."]
     pub fn cpDampedRotarySpringSetSpringTorqueFunc(
         constraint: *mut cpConstraint,
         springTorqueFunc: cpDampedRotarySpringTorqueFunc,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a damped rotary springs."]
     pub fn cpConstraintIsRotaryLimitJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a damped rotary limit joint."]
     pub fn cpRotaryLimitJointAlloc() -> *mut cpRotaryLimitJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a damped rotary limit joint."]
     pub fn cpRotaryLimitJointInit(
         joint: *mut cpRotaryLimitJoint,
@@ -2153,7 +2188,7 @@ extern "C" {
         max: cpFloat,
     ) -> *mut cpRotaryLimitJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a damped rotary limit joint."]
     pub fn cpRotaryLimitJointNew(
         a: *mut cpBody,
@@ -2162,31 +2197,31 @@ extern "C" {
         max: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the minimum distance the joint will maintain between the two anchors."]
     pub fn cpRotaryLimitJointGetMin(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the minimum distance the joint will maintain between the two anchors."]
     pub fn cpRotaryLimitJointSetMin(constraint: *mut cpConstraint, min: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the maximum distance the joint will maintain between the two anchors."]
     pub fn cpRotaryLimitJointGetMax(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the maximum distance the joint will maintain between the two anchors."]
     pub fn cpRotaryLimitJointSetMax(constraint: *mut cpConstraint, max: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a damped rotary springs."]
     pub fn cpConstraintIsRatchetJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a ratchet joint."]
     pub fn cpRatchetJointAlloc() -> *mut cpRatchetJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a ratched joint."]
     pub fn cpRatchetJointInit(
         joint: *mut cpRatchetJoint,
@@ -2196,7 +2231,7 @@ extern "C" {
         ratchet: cpFloat,
     ) -> *mut cpRatchetJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a ratchet joint."]
     pub fn cpRatchetJointNew(
         a: *mut cpBody,
@@ -2205,39 +2240,39 @@ extern "C" {
         ratchet: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the angle of the current ratchet tooth."]
     pub fn cpRatchetJointGetAngle(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the angle of the current ratchet tooth."]
     pub fn cpRatchetJointSetAngle(constraint: *mut cpConstraint, angle: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the phase offset of the ratchet."]
     pub fn cpRatchetJointGetPhase(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the phase offset of the ratchet."]
     pub fn cpRatchetJointSetPhase(constraint: *mut cpConstraint, phase: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the angular distance of each ratchet."]
     pub fn cpRatchetJointGetRatchet(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the angular distance of each ratchet."]
     pub fn cpRatchetJointSetRatchet(constraint: *mut cpConstraint, ratchet: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a damped rotary springs."]
     pub fn cpConstraintIsGearJoint(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a gear joint."]
     pub fn cpGearJointAlloc() -> *mut cpGearJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a gear joint."]
     pub fn cpGearJointInit(
         joint: *mut cpGearJoint,
@@ -2247,7 +2282,7 @@ extern "C" {
         ratio: cpFloat,
     ) -> *mut cpGearJoint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a gear joint."]
     pub fn cpGearJointNew(
         a: *mut cpBody,
@@ -2256,31 +2291,31 @@ extern "C" {
         ratio: cpFloat,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the phase offset of the gears."]
     pub fn cpGearJointGetPhase(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the phase offset of the gears."]
     pub fn cpGearJointSetPhase(constraint: *mut cpConstraint, phase: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the angular distance of each ratchet."]
     pub fn cpGearJointGetRatio(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the ratio of a gear joint."]
     pub fn cpGearJointSetRatio(constraint: *mut cpConstraint, ratio: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Check if a constraint is a damped rotary springs."]
     pub fn cpConstraintIsSimpleMotor(constraint: *const cpConstraint) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a simple motor."]
     pub fn cpSimpleMotorAlloc() -> *mut cpSimpleMotor;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " initialize a simple motor."]
     pub fn cpSimpleMotorInit(
         joint: *mut cpSimpleMotor,
@@ -2289,15 +2324,15 @@ extern "C" {
         rate: cpFloat,
     ) -> *mut cpSimpleMotor;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a simple motor."]
     pub fn cpSimpleMotorNew(a: *mut cpBody, b: *mut cpBody, rate: cpFloat) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Get the rate of the motor."]
     pub fn cpSimpleMotorGetRate(constraint: *const cpConstraint) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Set the rate of the motor."]
     pub fn cpSimpleMotorSetRate(constraint: *mut cpConstraint, rate: cpFloat);
 }
@@ -2441,122 +2476,122 @@ fn bindgen_test_layout_cpCollisionHandler() {
         )
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate a cpSpace."]
     pub fn cpSpaceAlloc() -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Initialize a cpSpace."]
     pub fn cpSpaceInit(space: *mut cpSpace) -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Allocate and initialize a cpSpace."]
     pub fn cpSpaceNew() -> *mut cpSpace;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy a cpSpace."]
     pub fn cpSpaceDestroy(space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Destroy and free a cpSpace."]
     pub fn cpSpaceFree(space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Number of iterations to use in the impulse solver to solve contacts and other constraints."]
     pub fn cpSpaceGetIterations(space: *const cpSpace) -> libc::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetIterations(space: *mut cpSpace, iterations: libc::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Gravity to pass to rigid bodies when integrating velocity."]
     pub fn cpSpaceGetGravity(space: *const cpSpace) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetGravity(space: *mut cpSpace, gravity: cpVect);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Damping rate expressed as the fraction of velocity bodies retain each second."]
     #[doc = " A value of 0.9 would mean that each body's velocity will drop 10% per second."]
     #[doc = " The default value is 1.0, meaning no damping is applied."]
     #[doc = " @note This damping value is different than those of cpDampedSpring and cpDampedRotarySpring."]
     pub fn cpSpaceGetDamping(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetDamping(space: *mut cpSpace, damping: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Speed threshold for a body to be considered idle."]
     #[doc = " The default value of 0 means to let the space guess a good threshold based on gravity."]
     pub fn cpSpaceGetIdleSpeedThreshold(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetIdleSpeedThreshold(space: *mut cpSpace, idleSpeedThreshold: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Time a group of bodies must remain idle in order to fall asleep."]
     #[doc = " Enabling sleeping also implicitly enables the the contact graph."]
     #[doc = " The default value of INFINITY disables the sleeping algorithm."]
     pub fn cpSpaceGetSleepTimeThreshold(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetSleepTimeThreshold(space: *mut cpSpace, sleepTimeThreshold: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Amount of encouraged penetration between colliding shapes."]
     #[doc = " Used to reduce oscillating contacts and keep the collision cache warm."]
     #[doc = " Defaults to 0.1. If you have poor simulation quality,"]
     #[doc = " increase this number as much as possible without allowing visible amounts of overlap."]
     pub fn cpSpaceGetCollisionSlop(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetCollisionSlop(space: *mut cpSpace, collisionSlop: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Determines how fast overlapping shapes are pushed apart."]
     #[doc = " Expressed as a fraction of the error remaining after each second."]
     #[doc = " Defaults to pow(1.0 - 0.1, 60.0) meaning that Chipmunk fixes 10% of overlap each frame at 60Hz."]
     pub fn cpSpaceGetCollisionBias(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetCollisionBias(space: *mut cpSpace, collisionBias: cpFloat);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Number of frames that contact information should persist."]
     #[doc = " Defaults to 3. There is probably never a reason to change this value."]
     pub fn cpSpaceGetCollisionPersistence(space: *const cpSpace) -> cpTimestamp;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetCollisionPersistence(space: *mut cpSpace, collisionPersistence: cpTimestamp);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " User definable data pointer."]
     #[doc = " Generally this points to your game's controller or game state"]
     #[doc = " class so you can access it when given a cpSpace reference in a callback."]
     pub fn cpSpaceGetUserData(space: *const cpSpace) -> cpDataPointer;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn cpSpaceSetUserData(space: *mut cpSpace, userData: cpDataPointer);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " The Space provided static body for a given cpSpace."]
     #[doc = " This is merely provided for convenience and you are not required to use it."]
     pub fn cpSpaceGetStaticBody(space: *const cpSpace) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Returns the current (or most recent) time step used with the given space."]
     #[doc = " Useful from callbacks if your time step is not a compile-time global."]
     pub fn cpSpaceGetCurrentTimeStep(space: *const cpSpace) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " returns true from inside a callback when objects cannot be added/removed."]
     pub fn cpSpaceIsLocked(space: *mut cpSpace) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Create or return the existing collision handler that is called for all collisions that are not handled by a more specific collision handler."]
     pub fn cpSpaceAddDefaultCollisionHandler(space: *mut cpSpace) -> *mut cpCollisionHandler;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Create or return the existing collision handler for the specified pair of collision types."]
     #[doc = " If wildcard handlers are used with either of the collision types, it's the responibility of the custom handler to invoke the wildcard handlers."]
     pub fn cpSpaceAddCollisionHandler(
@@ -2565,50 +2600,50 @@ extern "C" {
         b: cpCollisionType,
     ) -> *mut cpCollisionHandler;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Create or return the existing wildcard collision handler for the specified type."]
     pub fn cpSpaceAddWildcardHandler(
         space: *mut cpSpace,
         type_: cpCollisionType,
     ) -> *mut cpCollisionHandler;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Add a collision shape to the simulation."]
     #[doc = " If the shape is attached to a static body, it will be added as a static shape."]
     pub fn cpSpaceAddShape(space: *mut cpSpace, shape: *mut cpShape) -> *mut cpShape;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Add a rigid body to the simulation."]
     pub fn cpSpaceAddBody(space: *mut cpSpace, body: *mut cpBody) -> *mut cpBody;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Add a constraint to the simulation."]
     pub fn cpSpaceAddConstraint(
         space: *mut cpSpace,
         constraint: *mut cpConstraint,
     ) -> *mut cpConstraint;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Remove a collision shape from the simulation."]
     pub fn cpSpaceRemoveShape(space: *mut cpSpace, shape: *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Remove a rigid body from the simulation."]
     pub fn cpSpaceRemoveBody(space: *mut cpSpace, body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Remove a constraint from the simulation."]
     pub fn cpSpaceRemoveConstraint(space: *mut cpSpace, constraint: *mut cpConstraint);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Test if a collision shape has been added to the space."]
     pub fn cpSpaceContainsShape(space: *mut cpSpace, shape: *mut cpShape) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Test if a rigid body has been added to the space."]
     pub fn cpSpaceContainsBody(space: *mut cpSpace, body: *mut cpBody) -> cpBool;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Test if a constraint has been added to the space."]
     pub fn cpSpaceContainsConstraint(space: *mut cpSpace, constraint: *mut cpConstraint) -> cpBool;
 }
@@ -2616,7 +2651,7 @@ extern "C" {
 pub type cpPostStepFunc = ::core::option::Option<
     unsafe extern "C" fn(space: *mut cpSpace, key: *mut libc::c_void, data: *mut libc::c_void),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Schedule a post-step callback to be called when cpSpaceStep() finishes."]
     #[doc = " You can only register one callback per unique value for @c key."]
     #[doc = " Returns true only if @c key has never been scheduled before."]
@@ -2638,7 +2673,7 @@ pub type cpSpacePointQueryFunc = ::core::option::Option<
         data: *mut libc::c_void,
     ),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Query the space at a point and call @c func for each shape found."]
     pub fn cpSpacePointQuery(
         space: *mut cpSpace,
@@ -2649,7 +2684,7 @@ extern "C" {
         data: *mut libc::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Query the space at a point and return the nearest shape found. Returns NULL if no shapes were found."]
     pub fn cpSpacePointQueryNearest(
         space: *mut cpSpace,
@@ -2669,7 +2704,7 @@ pub type cpSpaceSegmentQueryFunc = ::core::option::Option<
         data: *mut libc::c_void,
     ),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a directed line segment query (like a raycast) against the space calling @c func for each shape intersected."]
     pub fn cpSpaceSegmentQuery(
         space: *mut cpSpace,
@@ -2681,7 +2716,7 @@ extern "C" {
         data: *mut libc::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a directed line segment query (like a raycast) against the space and return the first shape hit. Returns NULL if no shapes were hit."]
     pub fn cpSpaceSegmentQueryFirst(
         space: *mut cpSpace,
@@ -2695,7 +2730,7 @@ extern "C" {
 #[doc = " Rectangle Query callback function type."]
 pub type cpSpaceBBQueryFunc =
     ::core::option::Option<unsafe extern "C" fn(shape: *mut cpShape, data: *mut libc::c_void)>;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Perform a fast rectangle query on the space calling @c func for each shape found."]
     #[doc = " Only the shape's bounding boxes are checked for overlap, not their full shape."]
     pub fn cpSpaceBBQuery(
@@ -2714,7 +2749,7 @@ pub type cpSpaceShapeQueryFunc = ::core::option::Option<
         data: *mut libc::c_void,
     ),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Query a space for any shapes overlapping the given shape and call @c func for each shape found."]
     pub fn cpSpaceShapeQuery(
         space: *mut cpSpace,
@@ -2726,7 +2761,7 @@ extern "C" {
 #[doc = " Space/body iterator callback function type."]
 pub type cpSpaceBodyIteratorFunc =
     ::core::option::Option<unsafe extern "C" fn(body: *mut cpBody, data: *mut libc::c_void)>;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func for each body in the space."]
     pub fn cpSpaceEachBody(
         space: *mut cpSpace,
@@ -2737,7 +2772,7 @@ extern "C" {
 #[doc = " Space/body iterator callback function type."]
 pub type cpSpaceShapeIteratorFunc =
     ::core::option::Option<unsafe extern "C" fn(shape: *mut cpShape, data: *mut libc::c_void)>;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func for each shape in the space."]
     pub fn cpSpaceEachShape(
         space: *mut cpSpace,
@@ -2749,7 +2784,7 @@ extern "C" {
 pub type cpSpaceConstraintIteratorFunc = ::core::option::Option<
     unsafe extern "C" fn(constraint: *mut cpConstraint, data: *mut libc::c_void),
 >;
-extern "C" {
+extern "C-unwind" {
     #[doc = " Call @c func for each shape in the space."]
     pub fn cpSpaceEachConstraint(
         space: *mut cpSpace,
@@ -2757,23 +2792,23 @@ extern "C" {
         data: *mut libc::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Update the collision detection info for the static shapes in the space."]
     pub fn cpSpaceReindexStatic(space: *mut cpSpace);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Update the collision detection data for a specific shape in the space."]
     pub fn cpSpaceReindexShape(space: *mut cpSpace, shape: *mut cpShape);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Update the collision detection data for all shapes attached to a body."]
     pub fn cpSpaceReindexShapesForBody(space: *mut cpSpace, body: *mut cpBody);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Switch the space to use a spatial has as it's spatial index."]
     pub fn cpSpaceUseSpatialHash(space: *mut cpSpace, dim: cpFloat, count: libc::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Step the space forward in time by @c dt."]
     pub fn cpSpaceStep(space: *mut cpSpace, dt: cpFloat);
 }
@@ -3062,30 +3097,30 @@ fn bindgen_test_layout_cpSpaceDebugDrawOptions() {
         )
     );
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Debug draw the current state of the space using the supplied drawing options."]
     pub fn cpSpaceDebugDraw(space: *mut cpSpace, options: *mut cpSpaceDebugDrawOptions);
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the moment of inertia for a circle."]
     #[doc = " @c r1 and @c r2 are the inner and outer diameters. A solid circle has an inner diameter of 0."]
     pub fn cpMomentForCircle(m: cpFloat, r1: cpFloat, r2: cpFloat, offset: cpVect) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate area of a hollow circle."]
     #[doc = " @c r1 and @c r2 are the inner and outer diameters. A solid circle has an inner diameter of 0."]
     pub fn cpAreaForCircle(r1: cpFloat, r2: cpFloat) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the moment of inertia for a line segment."]
     #[doc = " Beveling radius is not supported."]
     pub fn cpMomentForSegment(m: cpFloat, a: cpVect, b: cpVect, radius: cpFloat) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the area of a fattened (capsule shaped) line segment."]
     pub fn cpAreaForSegment(a: cpVect, b: cpVect, radius: cpFloat) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the moment of inertia for a solid polygon shape assuming it's center of gravity is at it's centroid. The offset is added to each vertex."]
     pub fn cpMomentForPoly(
         m: cpFloat,
@@ -3095,24 +3130,24 @@ extern "C" {
         radius: cpFloat,
     ) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the signed area of a polygon. A Clockwise winding gives positive area."]
     #[doc = " This is probably backwards from what you expect, but matches Chipmunk's the winding for poly shapes."]
     pub fn cpAreaForPoly(count: libc::c_int, verts: *const cpVect, radius: cpFloat) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the natural centroid of a polygon."]
     pub fn cpCentroidForPoly(count: libc::c_int, verts: *const cpVect) -> cpVect;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the moment of inertia for a solid box."]
     pub fn cpMomentForBox(m: cpFloat, width: cpFloat, height: cpFloat) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the moment of inertia for a solid box."]
     pub fn cpMomentForBox2(m: cpFloat, box_: cpBB) -> cpFloat;
 }
-extern "C" {
+extern "C-unwind" {
     #[doc = " Calculate the convex hull of a given set of points. Returns the count of points in the hull."]
     #[doc = " @c result must be a pointer to a @c cpVect array with at least @c count elements. If @c verts == @c result, then @c verts will be reduced inplace."]
     #[doc = " @c first is an optional pointer to an integer to store where the first vertex in the hull came from (i.e. verts[first] == result[0])"]
@@ -6367,3 +6402,236 @@ fn bindgen_test_layout_cpPostStepCallback() {
         )
     );
 }
+#[repr(C)]
+pub struct cpPolyline {
+    pub count: libc::c_int,
+    pub capacity: libc::c_int,
+    pub verts: __IncompleteArrayField<cpVect>,
+}
+#[test]
+fn bindgen_test_layout_cpPolyline() {
+    assert_eq!(
+        ::core::mem::size_of::<cpPolyline>(),
+        8usize,
+        concat!("Size of: ", stringify!(cpPolyline))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<cpPolyline>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cpPolyline))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolyline>())).count as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolyline),
+            "::",
+            stringify!(count)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolyline>())).capacity as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolyline),
+            "::",
+            stringify!(capacity)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolyline>())).verts as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolyline),
+            "::",
+            stringify!(verts)
+        )
+    );
+}
+extern "C-unwind" {
+    #[doc = " Destroy and free a polyline instance."]
+    pub fn cpPolylineFree(line: *mut cpPolyline);
+}
+extern "C-unwind" {
+    #[doc = " Returns true if the first vertex is equal to the last."]
+    pub fn cpPolylineIsClosed(line: *mut cpPolyline) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = "Returns a copy of a polyline simplified by using the Douglas-Peucker algorithm."]
+    #[doc = "This works very well on smooth or gently curved shapes, but not well on straight edged or angular shapes."]
+    pub fn cpPolylineSimplifyCurves(line: *mut cpPolyline, tol: cpFloat) -> *mut cpPolyline;
+}
+extern "C-unwind" {
+    #[doc = "Returns a copy of a polyline simplified by discarding \"flat\" vertexes."]
+    #[doc = "This works well on straigt edged or angular shapes, not as well on smooth shapes."]
+    pub fn cpPolylineSimplifyVertexes(line: *mut cpPolyline, tol: cpFloat) -> *mut cpPolyline;
+}
+extern "C-unwind" {
+    #[doc = " Get the convex hull of a polyline as a looped polyline."]
+    pub fn cpPolylineToConvexHull(line: *mut cpPolyline, tol: cpFloat) -> *mut cpPolyline;
+}
+#[doc = " Polyline sets are collections of polylines, generally built by cpMarchSoft() or cpMarchHard()."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct cpPolylineSet {
+    pub count: libc::c_int,
+    pub capacity: libc::c_int,
+    pub lines: *mut *mut cpPolyline,
+}
+#[test]
+fn bindgen_test_layout_cpPolylineSet() {
+    assert_eq!(
+        ::core::mem::size_of::<cpPolylineSet>(),
+        16usize,
+        concat!("Size of: ", stringify!(cpPolylineSet))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<cpPolylineSet>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cpPolylineSet))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolylineSet>())).count as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolylineSet),
+            "::",
+            stringify!(count)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolylineSet>())).capacity as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolylineSet),
+            "::",
+            stringify!(capacity)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<cpPolylineSet>())).lines as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cpPolylineSet),
+            "::",
+            stringify!(lines)
+        )
+    );
+}
+extern "C-unwind" {
+    #[doc = " Allocate a new polyline set."]
+    pub fn cpPolylineSetAlloc() -> *mut cpPolylineSet;
+}
+extern "C-unwind" {
+    #[doc = " Initialize a new polyline set."]
+    pub fn cpPolylineSetInit(set: *mut cpPolylineSet) -> *mut cpPolylineSet;
+}
+extern "C-unwind" {
+    #[doc = " Allocate and initialize a polyline set."]
+    pub fn cpPolylineSetNew() -> *mut cpPolylineSet;
+}
+extern "C-unwind" {
+    #[doc = " Destroy a polyline set."]
+    pub fn cpPolylineSetDestroy(set: *mut cpPolylineSet, freePolylines: cpBool);
+}
+extern "C-unwind" {
+    #[doc = " Destroy and free a polyline set."]
+    pub fn cpPolylineSetFree(set: *mut cpPolylineSet, freePolylines: cpBool);
+}
+extern "C-unwind" {
+    #[doc = "Add a line segment to a polyline set."]
+    #[doc = "A segment will either start a new polyline, join two others, or add to or loop an existing polyline."]
+    #[doc = "This is mostly intended to be used as a callback directly from cpMarchSoft() or cpMarchHard()."]
+    pub fn cpPolylineSetCollectSegment(v0: cpVect, v1: cpVect, lines: *mut cpPolylineSet);
+}
+extern "C-unwind" {
+    #[doc = "Get an approximate convex decomposition from a polyline."]
+    #[doc = "Returns a cpPolylineSet of convex hulls that match the original shape to within 'tol'."]
+    #[doc = "NOTE: If the input is a self intersecting polygon, the output might end up overly simplified."]
+    pub fn cpPolylineConvexDecomposition(line: *mut cpPolyline, tol: cpFloat)
+        -> *mut cpPolylineSet;
+}
+#[doc = " Function type used as a callback from the marching squares algorithm to sample an image function."]
+#[doc = " It passes you the point to sample and your context pointer, and you return the density."]
+pub type cpMarchSampleFunc = ::core::option::Option<
+    unsafe extern "C" fn(point: cpVect, data: *mut libc::c_void) -> cpFloat,
+>;
+#[doc = " Function type used as a callback from the marching squares algorithm to output a line segment."]
+#[doc = " It passes you the two endpoints and your context pointer."]
+pub type cpMarchSegmentFunc = ::core::option::Option<
+    unsafe extern "C" fn(v0: cpVect, v1: cpVect, data: *mut libc::c_void),
+>;
+extern "C-unwind" {
+    #[doc = " Trace an anti-aliased contour of an image along a particular threshold."]
+    #[doc = " The given number of samples will be taken and spread across the bounding box area using the sampling function and context."]
+    #[doc = " The segment function will be called for each segment detected that lies along the density contour for @c threshold."]
+    pub fn cpMarchSoft(
+        bb: cpBB,
+        x_samples: libc::c_ulong,
+        y_samples: libc::c_ulong,
+        threshold: cpFloat,
+        segment: cpMarchSegmentFunc,
+        segment_data: *mut libc::c_void,
+        sample: cpMarchSampleFunc,
+        sample_data: *mut libc::c_void,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Trace an aliased curve of an image along a particular threshold."]
+    #[doc = " The given number of samples will be taken and spread across the bounding box area using the sampling function and context."]
+    #[doc = " The segment function will be called for each segment detected that lies along the density contour for @c threshold."]
+    pub fn cpMarchHard(
+        bb: cpBB,
+        x_samples: libc::c_ulong,
+        y_samples: libc::c_ulong,
+        threshold: cpFloat,
+        segment: cpMarchSegmentFunc,
+        segment_data: *mut libc::c_void,
+        sample: cpMarchSampleFunc,
+        sample_data: *mut libc::c_void,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Set the radius of a circle shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpCircleShapeSetRadius(shape: *mut cpShape, radius: cpFloat);
+}
+extern "C-unwind" {
+    #[doc = " Set the offset of a circle shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpCircleShapeSetOffset(shape: *mut cpShape, offset: cpVect);
+}
+extern "C-unwind" {
+    #[doc = " Set the endpoints of a segment shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpSegmentShapeSetEndpoints(shape: *mut cpShape, a: cpVect, b: cpVect);
+}
+extern "C-unwind" {
+    #[doc = " Set the radius of a segment shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpSegmentShapeSetRadius(shape: *mut cpShape, radius: cpFloat);
+}
+extern "C-unwind" {
+    #[doc = " Set the vertexes of a poly shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpPolyShapeSetVerts(
+        shape: *mut cpShape,
+        count: libc::c_int,
+        verts: *mut cpVect,
+        transform: cpTransform,
+    );
+}
+extern "C-unwind" {
+    pub fn cpPolyShapeSetVertsRaw(shape: *mut cpShape, count: libc::c_int, verts: *mut cpVect);
+}
+extern "C-unwind" {
+    #[doc = " Set the radius of a poly shape."]
+    #[doc = " This operation is unsafe. It changes the collision geometry without updating the simulation, so objects may end up overlapping or tunneling."]
+    pub fn cpPolyShapeSetRadius(shape: *mut cpShape, radius: cpFloat);
+}
@@ -0,0 +1,505 @@
+// Non-inline copies of the static inline functions in Chipmunk's headers.
+// bindgen can't generate bindings for static inline functions, so each one is exported here as
+// chipmunk_rs_<name>() and bound under its original name by src/inline.rs.
+
+#include "chipmunk/chipmunk.h"
+
+// chipmunk_types.h
+
+cpFloat
+chipmunk_rs_cpfmax(cpFloat a, cpFloat b)
+{
+	return cpfmax(a, b);
+}
+
+cpFloat
+chipmunk_rs_cpfmin(cpFloat a, cpFloat b)
+{
+	return cpfmin(a, b);
+}
+
+cpFloat
+chipmunk_rs_cpfabs(cpFloat f)
+{
+	return cpfabs(f);
+}
+
+cpFloat
+chipmunk_rs_cpfclamp(cpFloat f, cpFloat min, cpFloat max)
+{
+	return cpfclamp(f, min, max);
+}
+
+cpFloat
+chipmunk_rs_cpfclamp01(cpFloat f)
+{
+	return cpfclamp01(f);
+}
+
+cpFloat
+chipmunk_rs_cpflerp(cpFloat f1, cpFloat f2, cpFloat t)
+{
+	return cpflerp(f1, f2, t);
+}
+
+cpFloat
+chipmunk_rs_cpflerpconst(cpFloat f1, cpFloat f2, cpFloat d)
+{
+	return cpflerpconst(f1, f2, d);
+}
+
+// cpVect.h
+
+cpVect
+chipmunk_rs_cpv(const cpFloat x, const cpFloat y)
+{
+	return cpv(x, y);
+}
+
+cpBool
+chipmunk_rs_cpveql(const cpVect v1, const cpVect v2)
+{
+	return cpveql(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvadd(const cpVect v1, const cpVect v2)
+{
+	return cpvadd(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvsub(const cpVect v1, const cpVect v2)
+{
+	return cpvsub(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvneg(const cpVect v)
+{
+	return cpvneg(v);
+}
+
+cpVect
+chipmunk_rs_cpvmult(const cpVect v, const cpFloat s)
+{
+	return cpvmult(v, s);
+}
+
+cpFloat
+chipmunk_rs_cpvdot(const cpVect v1, const cpVect v2)
+{
+	return cpvdot(v1, v2);
+}
+
+cpFloat
+chipmunk_rs_cpvcross(const cpVect v1, const cpVect v2)
+{
+	return cpvcross(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvperp(const cpVect v)
+{
+	return cpvperp(v);
+}
+
+cpVect
+chipmunk_rs_cpvrperp(const cpVect v)
+{
+	return cpvrperp(v);
+}
+
+cpVect
+chipmunk_rs_cpvproject(const cpVect v1, const cpVect v2)
+{
+	return cpvproject(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvforangle(const cpFloat a)
+{
+	return cpvforangle(a);
+}
+
+cpFloat
+chipmunk_rs_cpvtoangle(const cpVect v)
+{
+	return cpvtoangle(v);
+}
+
+cpVect
+chipmunk_rs_cpvrotate(const cpVect v1, const cpVect v2)
+{
+	return cpvrotate(v1, v2);
+}
+
+cpVect
+chipmunk_rs_cpvunrotate(const cpVect v1, const cpVect v2)
+{
+	return cpvunrotate(v1, v2);
+}
+
+cpFloat
+chipmunk_rs_cpvlengthsq(const cpVect v)
+{
+	return cpvlengthsq(v);
+}
+
+cpFloat
+chipmunk_rs_cpvlength(const cpVect v)
+{
+	return cpvlength(v);
+}
+
+cpVect
+chipmunk_rs_cpvlerp(const cpVect v1, const cpVect v2, const cpFloat t)
+{
+	return cpvlerp(v1, v2, t);
+}
+
+cpVect
+chipmunk_rs_cpvnormalize(const cpVect v)
+{
+	return cpvnormalize(v);
+}
+
+cpVect
+chipmunk_rs_cpvslerp(const cpVect v1, const cpVect v2, const cpFloat t)
+{
+	return cpvslerp(v1, v2, t);
+}
+
+cpVect
+chipmunk_rs_cpvslerpconst(const cpVect v1, const cpVect v2, const cpFloat a)
+{
+	return cpvslerpconst(v1, v2, a);
+}
+
+cpVect
+chipmunk_rs_cpvclamp(const cpVect v, const cpFloat len)
+{
+	return cpvclamp(v, len);
+}
+
+cpVect
+chipmunk_rs_cpvlerpconst(cpVect v1, cpVect v2, cpFloat d)
+{
+	return cpvlerpconst(v1, v2, d);
+}
+
+cpFloat
+chipmunk_rs_cpvdist(const cpVect v1, const cpVect v2)
+{
+	return cpvdist(v1, v2);
+}
+
+cpFloat
+chipmunk_rs_cpvdistsq(const cpVect v1, const cpVect v2)
+{
+	return cpvdistsq(v1, v2);
+}
+
+cpBool
+chipmunk_rs_cpvnear(const cpVect v1, const cpVect v2, const cpFloat dist)
+{
+	return cpvnear(v1, v2, dist);
+}
+
+cpMat2x2
+chipmunk_rs_cpMat2x2New(cpFloat a, cpFloat b, cpFloat c, cpFloat d)
+{
+	return cpMat2x2New(a, b, c, d);
+}
+
+cpVect
+chipmunk_rs_cpMat2x2Transform(cpMat2x2 m, cpVect v)
+{
+	return cpMat2x2Transform(m, v);
+}
+
+// cpBB.h
+
+cpBB
+chipmunk_rs_cpBBNew(const cpFloat l, const cpFloat b, const cpFloat r, const cpFloat t)
+{
+	return cpBBNew(l, b, r, t);
+}
+
+cpBB
+chipmunk_rs_cpBBNewForExtents(const cpVect c, const cpFloat hw, const cpFloat hh)
+{
+	return cpBBNewForExtents(c, hw, hh);
+}
+
+cpBB
+chipmunk_rs_cpBBNewForCircle(const cpVect p, const cpFloat r)
+{
+	return cpBBNewForCircle(p, r);
+}
+
+cpBool
+chipmunk_rs_cpBBIntersects(const cpBB a, const cpBB b)
+{
+	return cpBBIntersects(a, b);
+}
+
+cpBool
+chipmunk_rs_cpBBContainsBB(const cpBB bb, const cpBB other)
+{
+	return cpBBContainsBB(bb, other);
+}
+
+cpBool
+chipmunk_rs_cpBBContainsVect(const cpBB bb, const cpVect v)
+{
+	return cpBBContainsVect(bb, v);
+}
+
+cpBB
+chipmunk_rs_cpBBMerge(const cpBB a, const cpBB b)
+{
+	return cpBBMerge(a, b);
+}
+
+cpBB
+chipmunk_rs_cpBBExpand(const cpBB bb, const cpVect v)
+{
+	return cpBBExpand(bb, v);
+}
+
+cpVect
+chipmunk_rs_cpBBCenter(cpBB bb)
+{
+	return cpBBCenter(bb);
+}
+
+cpFloat
+chipmunk_rs_cpBBArea(cpBB bb)
+{
+	return cpBBArea(bb);
+}
+
+cpFloat
+chipmunk_rs_cpBBMergedArea(cpBB a, cpBB b)
+{
+	return cpBBMergedArea(a, b);
+}
+
+cpFloat
+chipmunk_rs_cpBBSegmentQuery(cpBB bb, cpVect a, cpVect b)
+{
+	return cpBBSegmentQuery(bb, a, b);
+}
+
+cpBool
+chipmunk_rs_cpBBIntersectsSegment(cpBB bb, cpVect a, cpVect b)
+{
+	return cpBBIntersectsSegment(bb, a, b);
+}
+
+cpVect
+chipmunk_rs_cpBBClampVect(const cpBB bb, const cpVect v)
+{
+	return cpBBClampVect(bb, v);
+}
+
+cpVect
+chipmunk_rs_cpBBWrapVect(const cpBB bb, const cpVect v)
+{
+	return cpBBWrapVect(bb, v);
+}
+
+cpBB
+chipmunk_rs_cpBBOffset(const cpBB bb, const cpVect v)
+{
+	return cpBBOffset(bb, v);
+}
+
+// cpTransform.h
+
+cpTransform
+chipmunk_rs_cpTransformNew(cpFloat a, cpFloat b, cpFloat c, cpFloat d, cpFloat tx, cpFloat ty)
+{
+	return cpTransformNew(a, b, c, d, tx, ty);
+}
+
+cpTransform
+chipmunk_rs_cpTransformNewTranspose(cpFloat a, cpFloat c, cpFloat tx, cpFloat b, cpFloat d, cpFloat ty)
+{
+	return cpTransformNewTranspose(a, c, tx, b, d, ty);
+}
+
+cpTransform
+chipmunk_rs_cpTransformInverse(cpTransform t)
+{
+	return cpTransformInverse(t);
+}
+
+cpTransform
+chipmunk_rs_cpTransformMult(cpTransform t1, cpTransform t2)
+{
+	return cpTransformMult(t1, t2);
+}
+
+cpVect
+chipmunk_rs_cpTransformPoint(cpTransform t, cpVect p)
+{
+	return cpTransformPoint(t, p);
+}
+
+cpVect
+chipmunk_rs_cpTransformVect(cpTransform t, cpVect v)
+{
+	return cpTransformVect(t, v);
+}
+
+cpBB
+chipmunk_rs_cpTransformbBB(cpTransform t, cpBB bb)
+{
+	return cpTransformbBB(t, bb);
+}
+
+cpTransform
+chipmunk_rs_cpTransformTranslate(cpVect translate)
+{
+	return cpTransformTranslate(translate);
+}
+
+cpTransform
+chipmunk_rs_cpTransformScale(cpFloat scaleX, cpFloat scaleY)
+{
+	return cpTransformScale(scaleX, scaleY);
+}
+
+cpTransform
+chipmunk_rs_cpTransformRotate(cpFloat radians)
+{
+	return cpTransformRotate(radians);
+}
+
+cpTransform
+chipmunk_rs_cpTransformRigid(cpVect translate, cpFloat radians)
+{
+	return cpTransformRigid(translate, radians);
+}
+
+cpTransform
+chipmunk_rs_cpTransformRigidInverse(cpTransform t)
+{
+	return cpTransformRigidInverse(t);
+}
+
+cpTransform
+chipmunk_rs_cpTransformWrap(cpTransform outer, cpTransform inner)
+{
+	return cpTransformWrap(outer, inner);
+}
+
+cpTransform
+chipmunk_rs_cpTransformWrapInverse(cpTransform outer, cpTransform inner)
+{
+	return cpTransformWrapInverse(outer, inner);
+}
+
+cpTransform
+chipmunk_rs_cpTransformOrtho(cpBB bb)
+{
+	return cpTransformOrtho(bb);
+}
+
+cpTransform
+chipmunk_rs_cpTransformBoneScale(cpVect v0, cpVect v1)
+{
+	return cpTransformBoneScale(v0, v1);
+}
+
+cpTransform
+chipmunk_rs_cpTransformAxialScale(cpVect axis, cpVect pivot, cpFloat scale)
+{
+	return cpTransformAxialScale(axis, pivot, scale);
+}
+
+// cpSpatialIndex.h
+
+void
+chipmunk_rs_cpSpatialIndexDestroy(cpSpatialIndex *index)
+{
+	cpSpatialIndexDestroy(index);
+}
+
+int
+chipmunk_rs_cpSpatialIndexCount(cpSpatialIndex *index)
+{
+	return cpSpatialIndexCount(index);
+}
+
+void
+chipmunk_rs_cpSpatialIndexEach(cpSpatialIndex *index, cpSpatialIndexIteratorFunc func, void *data)
+{
+	cpSpatialIndexEach(index, func, data);
+}
+
+cpBool
+chipmunk_rs_cpSpatialIndexContains(cpSpatialIndex *index, void *obj, cpHashValue hashid)
+{
+	return cpSpatialIndexContains(index, obj, hashid);
+}
+
+void
+chipmunk_rs_cpSpatialIndexInsert(cpSpatialIndex *index, void *obj, cpHashValue hashid)
+{
+	cpSpatialIndexInsert(index, obj, hashid);
+}
+
+void
+chipmunk_rs_cpSpatialIndexRemove(cpSpatialIndex *index, void *obj, cpHashValue hashid)
+{
+	cpSpatialIndexRemove(index, obj, hashid);
+}
+
+void
+chipmunk_rs_cpSpatialIndexReindex(cpSpatialIndex *index)
+{
+	cpSpatialIndexReindex(index);
+}
+
+void
+chipmunk_rs_cpSpatialIndexReindexObject(cpSpatialIndex *index, void *obj, cpHashValue hashid)
+{
+	cpSpatialIndexReindexObject(index, obj, hashid);
+}
+
+void
+chipmunk_rs_cpSpatialIndexQuery(cpSpatialIndex *index, void *obj, cpBB bb, cpSpatialIndexQueryFunc func, void *data)
+{
+	cpSpatialIndexQuery(index, obj, bb, func, data);
+}
+
+void
+chipmunk_rs_cpSpatialIndexSegmentQuery(cpSpatialIndex *index, void *obj, cpVect a, cpVect b, cpFloat t_exit, cpSpatialIndexSegmentQueryFunc func, void *data)
+{
+	cpSpatialIndexSegmentQuery(index, obj, a, b, t_exit, func, data);
+}
+
+void
+chipmunk_rs_cpSpatialIndexReindexQuery(cpSpatialIndex *index, cpSpatialIndexQueryFunc func, void *data)
+{
+	cpSpatialIndexReindexQuery(index, func, data);
+}
+
+// cpShape.h
+
+cpShapeFilter
+chipmunk_rs_cpShapeFilterNew(cpGroup group, cpBitmask categories, cpBitmask mask)
+{
+	return cpShapeFilterNew(group, categories, mask);
+}
+
+// chipmunk.h
+
+cpVect
+chipmunk_rs_cpClosetPointOnSegment(const cpVect p, const cpVect a, const cpVect b)
+{
+	return cpClosetPointOnSegment(p, a, b);
+}
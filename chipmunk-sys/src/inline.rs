@@ -0,0 +1,482 @@
+//! Bindings for the static inline functions in Chipmunk's headers.
+//! They are compiled into real symbols by src/inline.c.
+
+use super::*;
+
+// chipmunk_types.h
+extern "C-unwind" {
+    #[doc = " Return the max of two cpFloats."]
+    #[link_name = "chipmunk_rs_cpfmax"]
+    pub fn cpfmax(a: cpFloat, b: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Return the min of two cpFloats."]
+    #[link_name = "chipmunk_rs_cpfmin"]
+    pub fn cpfmin(a: cpFloat, b: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Return the absolute value of a cpFloat."]
+    #[link_name = "chipmunk_rs_cpfabs"]
+    pub fn cpfabs(f: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Clamp @c f to be between @c min and @c max."]
+    #[link_name = "chipmunk_rs_cpfclamp"]
+    pub fn cpfclamp(f: cpFloat, min: cpFloat, max: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Clamp @c f to be between 0 and 1."]
+    #[link_name = "chipmunk_rs_cpfclamp01"]
+    pub fn cpfclamp01(f: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Linearly interpolate (or extrapolate) between @c f1 and @c f2 by @c t percent."]
+    #[link_name = "chipmunk_rs_cpflerp"]
+    pub fn cpflerp(f1: cpFloat, f2: cpFloat, t: cpFloat) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Linearly interpolate from @c f1 to @c f2 by no more than @c d."]
+    #[link_name = "chipmunk_rs_cpflerpconst"]
+    pub fn cpflerpconst(f1: cpFloat, f2: cpFloat, d: cpFloat) -> cpFloat;
+}
+
+// cpVect.h
+extern "C-unwind" {
+    #[doc = " Convenience constructor for cpVect structs."]
+    #[link_name = "chipmunk_rs_cpv"]
+    pub fn cpv(x: cpFloat, y: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Check if two vectors are equal. (Be careful when comparing floating point numbers!)"]
+    #[link_name = "chipmunk_rs_cpveql"]
+    pub fn cpveql(v1: cpVect, v2: cpVect) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Add two vectors"]
+    #[link_name = "chipmunk_rs_cpvadd"]
+    pub fn cpvadd(v1: cpVect, v2: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Subtract two vectors."]
+    #[link_name = "chipmunk_rs_cpvsub"]
+    pub fn cpvsub(v1: cpVect, v2: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Negate a vector."]
+    #[link_name = "chipmunk_rs_cpvneg"]
+    pub fn cpvneg(v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Scalar multiplication."]
+    #[link_name = "chipmunk_rs_cpvmult"]
+    pub fn cpvmult(v: cpVect, s: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Vector dot product."]
+    #[link_name = "chipmunk_rs_cpvdot"]
+    pub fn cpvdot(v1: cpVect, v2: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " 2D vector cross product analog."]
+    #[doc = " The cross product of 2D vectors results in a 3D vector with only a z component."]
+    #[doc = " This function returns the magnitude of the z value."]
+    #[link_name = "chipmunk_rs_cpvcross"]
+    pub fn cpvcross(v1: cpVect, v2: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Returns a perpendicular vector. (90 degree rotation)"]
+    #[link_name = "chipmunk_rs_cpvperp"]
+    pub fn cpvperp(v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns a perpendicular vector. (-90 degree rotation)"]
+    #[link_name = "chipmunk_rs_cpvrperp"]
+    pub fn cpvrperp(v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the vector projection of v1 onto v2."]
+    #[link_name = "chipmunk_rs_cpvproject"]
+    pub fn cpvproject(v1: cpVect, v2: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the unit length vector for the given angle (in radians)."]
+    #[link_name = "chipmunk_rs_cpvforangle"]
+    pub fn cpvforangle(a: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the angular direction v is pointing in (in radians)."]
+    #[link_name = "chipmunk_rs_cpvtoangle"]
+    pub fn cpvtoangle(v: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Uses complex number multiplication to rotate v1 by v2. Scaling will occur if v1 is not a unit vector."]
+    #[link_name = "chipmunk_rs_cpvrotate"]
+    pub fn cpvrotate(v1: cpVect, v2: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Inverse of cpvrotate()."]
+    #[link_name = "chipmunk_rs_cpvunrotate"]
+    pub fn cpvunrotate(v1: cpVect, v2: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the squared length of v. Faster than cpvlength() when you only need to compare lengths."]
+    #[link_name = "chipmunk_rs_cpvlengthsq"]
+    pub fn cpvlengthsq(v: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Returns the length of v."]
+    #[link_name = "chipmunk_rs_cpvlength"]
+    pub fn cpvlength(v: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Linearly interpolate between v1 and v2."]
+    #[link_name = "chipmunk_rs_cpvlerp"]
+    pub fn cpvlerp(v1: cpVect, v2: cpVect, t: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns a normalized copy of v."]
+    #[link_name = "chipmunk_rs_cpvnormalize"]
+    pub fn cpvnormalize(v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Spherical linearly interpolate between v1 and v2."]
+    #[link_name = "chipmunk_rs_cpvslerp"]
+    pub fn cpvslerp(v1: cpVect, v2: cpVect, t: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Spherical linearly interpolate between v1 towards v2 by no more than angle a radians"]
+    #[link_name = "chipmunk_rs_cpvslerpconst"]
+    pub fn cpvslerpconst(v1: cpVect, v2: cpVect, a: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Clamp v to length len."]
+    #[link_name = "chipmunk_rs_cpvclamp"]
+    pub fn cpvclamp(v: cpVect, len: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Linearly interpolate between v1 towards v2 by distance d."]
+    #[link_name = "chipmunk_rs_cpvlerpconst"]
+    pub fn cpvlerpconst(v1: cpVect, v2: cpVect, d: cpFloat) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the distance between v1 and v2."]
+    #[link_name = "chipmunk_rs_cpvdist"]
+    pub fn cpvdist(v1: cpVect, v2: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Returns the squared distance between v1 and v2. Faster than cpvdist() when you only need to compare distances."]
+    #[link_name = "chipmunk_rs_cpvdistsq"]
+    pub fn cpvdistsq(v1: cpVect, v2: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Returns true if the distance between v1 and v2 is less than dist."]
+    #[link_name = "chipmunk_rs_cpvnear"]
+    pub fn cpvnear(v1: cpVect, v2: cpVect, dist: cpFloat) -> cpBool;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpMat2x2New"]
+    pub fn cpMat2x2New(a: cpFloat, b: cpFloat, c: cpFloat, d: cpFloat) -> cpMat2x2;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpMat2x2Transform"]
+    pub fn cpMat2x2Transform(m: cpMat2x2, v: cpVect) -> cpVect;
+}
+
+// cpBB.h
+extern "C-unwind" {
+    #[doc = " Convenience constructor for cpBB structs."]
+    #[link_name = "chipmunk_rs_cpBBNew"]
+    pub fn cpBBNew(l: cpFloat, b: cpFloat, r: cpFloat, t: cpFloat) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Constructs a cpBB centered on a point with the given extents (half sizes)."]
+    #[link_name = "chipmunk_rs_cpBBNewForExtents"]
+    pub fn cpBBNewForExtents(c: cpVect, hw: cpFloat, hh: cpFloat) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Constructs a cpBB for a circle with the given position and radius."]
+    #[link_name = "chipmunk_rs_cpBBNewForCircle"]
+    pub fn cpBBNewForCircle(p: cpVect, r: cpFloat) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Returns true if @c a and @c b intersect."]
+    #[link_name = "chipmunk_rs_cpBBIntersects"]
+    pub fn cpBBIntersects(a: cpBB, b: cpBB) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Returns true if @c other lies completely within @c bb."]
+    #[link_name = "chipmunk_rs_cpBBContainsBB"]
+    pub fn cpBBContainsBB(bb: cpBB, other: cpBB) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Returns true if @c bb contains @c v."]
+    #[link_name = "chipmunk_rs_cpBBContainsVect"]
+    pub fn cpBBContainsVect(bb: cpBB, v: cpVect) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Returns a bounding box that holds both bounding boxes."]
+    #[link_name = "chipmunk_rs_cpBBMerge"]
+    pub fn cpBBMerge(a: cpBB, b: cpBB) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Returns a bounding box that holds both @c bb and @c v."]
+    #[link_name = "chipmunk_rs_cpBBExpand"]
+    pub fn cpBBExpand(bb: cpBB, v: cpVect) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Returns the center of a bounding box."]
+    #[link_name = "chipmunk_rs_cpBBCenter"]
+    pub fn cpBBCenter(bb: cpBB) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns the area of the bounding box."]
+    #[link_name = "chipmunk_rs_cpBBArea"]
+    pub fn cpBBArea(bb: cpBB) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Merges @c a and @c b and returns the area of the merged bounding box."]
+    #[link_name = "chipmunk_rs_cpBBMergedArea"]
+    pub fn cpBBMergedArea(a: cpBB, b: cpBB) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Returns the fraction along the segment query the cpBB is hit. Returns INFINITY if it doesn't hit."]
+    #[link_name = "chipmunk_rs_cpBBSegmentQuery"]
+    pub fn cpBBSegmentQuery(bb: cpBB, a: cpVect, b: cpVect) -> cpFloat;
+}
+extern "C-unwind" {
+    #[doc = " Return true if the bounding box intersects the line segment with ends @c a and @c b."]
+    #[link_name = "chipmunk_rs_cpBBIntersectsSegment"]
+    pub fn cpBBIntersectsSegment(bb: cpBB, a: cpVect, b: cpVect) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Clamp a vector to a bounding box."]
+    #[link_name = "chipmunk_rs_cpBBClampVect"]
+    pub fn cpBBClampVect(bb: cpBB, v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Wrap a vector to a bounding box."]
+    #[link_name = "chipmunk_rs_cpBBWrapVect"]
+    pub fn cpBBWrapVect(bb: cpBB, v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Returns a bounding box offseted by @c v."]
+    #[link_name = "chipmunk_rs_cpBBOffset"]
+    pub fn cpBBOffset(bb: cpBB, v: cpVect) -> cpBB;
+}
+
+// cpTransform.h
+extern "C-unwind" {
+    #[doc = " Construct a new transform matrix."]
+    #[doc = " (a, b) is the x basis vector."]
+    #[doc = " (c, d) is the y basis vector."]
+    #[doc = " (tx, ty) is the translation."]
+    #[link_name = "chipmunk_rs_cpTransformNew"]
+    pub fn cpTransformNew(
+        a: cpFloat,
+        b: cpFloat,
+        c: cpFloat,
+        d: cpFloat,
+        tx: cpFloat,
+        ty: cpFloat,
+    ) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Construct a new transform matrix in transposed order."]
+    #[link_name = "chipmunk_rs_cpTransformNewTranspose"]
+    pub fn cpTransformNewTranspose(
+        a: cpFloat,
+        c: cpFloat,
+        tx: cpFloat,
+        b: cpFloat,
+        d: cpFloat,
+        ty: cpFloat,
+    ) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Get the inverse of a transform matrix."]
+    #[link_name = "chipmunk_rs_cpTransformInverse"]
+    pub fn cpTransformInverse(t: cpTransform) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Multiply two transformation matrices."]
+    #[link_name = "chipmunk_rs_cpTransformMult"]
+    pub fn cpTransformMult(t1: cpTransform, t2: cpTransform) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Transform an absolute point. (i.e. a vertex)"]
+    #[link_name = "chipmunk_rs_cpTransformPoint"]
+    pub fn cpTransformPoint(t: cpTransform, p: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Transform a vector (i.e. a normal)"]
+    #[link_name = "chipmunk_rs_cpTransformVect"]
+    pub fn cpTransformVect(t: cpTransform, v: cpVect) -> cpVect;
+}
+extern "C-unwind" {
+    #[doc = " Transform a cpBB."]
+    #[link_name = "chipmunk_rs_cpTransformbBB"]
+    pub fn cpTransformbBB(t: cpTransform, bb: cpBB) -> cpBB;
+}
+extern "C-unwind" {
+    #[doc = " Create a transation matrix."]
+    #[link_name = "chipmunk_rs_cpTransformTranslate"]
+    pub fn cpTransformTranslate(translate: cpVect) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Create a scale matrix."]
+    #[link_name = "chipmunk_rs_cpTransformScale"]
+    pub fn cpTransformScale(scaleX: cpFloat, scaleY: cpFloat) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Create a rotation matrix."]
+    #[link_name = "chipmunk_rs_cpTransformRotate"]
+    pub fn cpTransformRotate(radians: cpFloat) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Create a rigid transformation matrix. (transation + rotation)"]
+    #[link_name = "chipmunk_rs_cpTransformRigid"]
+    pub fn cpTransformRigid(translate: cpVect, radians: cpFloat) -> cpTransform;
+}
+extern "C-unwind" {
+    #[doc = " Fast inverse of a rigid transformation matrix."]
+    #[link_name = "chipmunk_rs_cpTransformRigidInverse"]
+    pub fn cpTransformRigidInverse(t: cpTransform) -> cpTransform;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpTransformWrap"]
+    pub fn cpTransformWrap(outer: cpTransform, inner: cpTransform) -> cpTransform;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpTransformWrapInverse"]
+    pub fn cpTransformWrapInverse(outer: cpTransform, inner: cpTransform) -> cpTransform;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpTransformOrtho"]
+    pub fn cpTransformOrtho(bb: cpBB) -> cpTransform;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpTransformBoneScale"]
+    pub fn cpTransformBoneScale(v0: cpVect, v1: cpVect) -> cpTransform;
+}
+extern "C-unwind" {
+    #[link_name = "chipmunk_rs_cpTransformAxialScale"]
+    pub fn cpTransformAxialScale(axis: cpVect, pivot: cpVect, scale: cpFloat) -> cpTransform;
+}
+
+// cpSpatialIndex.h
+extern "C-unwind" {
+    #[doc = " Destroy a spatial index."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexDestroy"]
+    pub fn cpSpatialIndexDestroy(index: *mut cpSpatialIndex);
+}
+extern "C-unwind" {
+    #[doc = " Get the number of objects in the spatial index."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexCount"]
+    pub fn cpSpatialIndexCount(index: *mut cpSpatialIndex) -> libc::c_int;
+}
+extern "C-unwind" {
+    #[doc = " Iterate the objects in the spatial index. @c func will be called once for each object."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexEach"]
+    pub fn cpSpatialIndexEach(
+        index: *mut cpSpatialIndex,
+        func: cpSpatialIndexIteratorFunc,
+        data: *mut libc::c_void,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Returns true if the spatial index contains the given object."]
+    #[doc = " Most spatial indexes use hashed storage, so you must provide a hash value too."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexContains"]
+    pub fn cpSpatialIndexContains(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        hashid: cpHashValue,
+    ) -> cpBool;
+}
+extern "C-unwind" {
+    #[doc = " Add an object to a spatial index."]
+    #[doc = " Most spatial indexes use hashed storage, so you must provide a hash value too."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexInsert"]
+    pub fn cpSpatialIndexInsert(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        hashid: cpHashValue,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Remove an object from a spatial index."]
+    #[doc = " Most spatial indexes use hashed storage, so you must provide a hash value too."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexRemove"]
+    pub fn cpSpatialIndexRemove(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        hashid: cpHashValue,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Perform a full reindex of a spatial index."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexReindex"]
+    pub fn cpSpatialIndexReindex(index: *mut cpSpatialIndex);
+}
+extern "C-unwind" {
+    #[doc = " Reindex a single object in the spatial index."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexReindexObject"]
+    pub fn cpSpatialIndexReindexObject(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        hashid: cpHashValue,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Perform a rectangle query against the spatial index, calling @c func for each potential match."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexQuery"]
+    pub fn cpSpatialIndexQuery(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        bb: cpBB,
+        func: cpSpatialIndexQueryFunc,
+        data: *mut libc::c_void,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Perform a segment query against the spatial index, calling @c func for each potential match."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexSegmentQuery"]
+    pub fn cpSpatialIndexSegmentQuery(
+        index: *mut cpSpatialIndex,
+        obj: *mut libc::c_void,
+        a: cpVect,
+        b: cpVect,
+        t_exit: cpFloat,
+        func: cpSpatialIndexSegmentQueryFunc,
+        data: *mut libc::c_void,
+    );
+}
+extern "C-unwind" {
+    #[doc = " Simultaneously reindex and find all colliding objects."]
+    #[doc = " @c func will be called once for each potentially overlapping pair of objects found."]
+    #[doc = " If the spatial index was initialized with a static index, it will collide it's objects against that as well."]
+    #[link_name = "chipmunk_rs_cpSpatialIndexReindexQuery"]
+    pub fn cpSpatialIndexReindexQuery(
+        index: *mut cpSpatialIndex,
+        func: cpSpatialIndexQueryFunc,
+        data: *mut libc::c_void,
+    );
+}
+
+// cpShape.h
+extern "C-unwind" {
+    #[doc = " Create a new collision filter."]
+    #[link_name = "chipmunk_rs_cpShapeFilterNew"]
+    pub fn cpShapeFilterNew(
+        group: cpGroup,
+        categories: cpBitmask,
+        mask: cpBitmask,
+    ) -> cpShapeFilter;
+}
+
+// chipmunk.h
+extern "C-unwind" {
+    #[doc = " Returns the closest point on the line segment ab, to the point p."]
+    #[link_name = "chipmunk_rs_cpClosetPointOnSegment"]
+    pub fn cpClosetPointOnSegment(p: cpVect, a: cpVect, b: cpVect) -> cpVect;
+}
@@ -7,3 +7,14 @@
 #![no_std]
 
 include!(concat!(env!("OUT_DIR"), "/chipmunk_bindings.rs"));
+
+/// Whether Chipmunk was compiled from the bundled sources, with unwind tables so that a Rust panic can unwind
+/// through its functions. A library found any other way may have been built without them.
+pub const CAN_UNWIND: bool = cfg!(chipmunk_bundled);
+
+mod inline;
+pub use inline::*;
+#[cfg(feature = "private-api")]
+mod private;
+#[cfg(feature = "private-api")]
+pub use private::*;
@@ -0,0 +1,21 @@
+// Replaces the cpMessage() from chipmunk.c when the "message-handler" feature is enabled.
+// Rust can't define variadic functions, so the message is formatted here and passed on to
+// chipmunk_rs_message(), which the chipmunk crate implements.
+
+#include <stdarg.h>
+#include <stdio.h>
+
+void chipmunk_rs_message(const char *condition, const char *file, int line, int isError, int isHardError, const char *message);
+
+void
+cpMessage(const char *condition, const char *file, int line, int isError, int isHardError, const char *message, ...)
+{
+	char buffer[1024];
+
+	va_list vargs;
+	va_start(vargs, message);
+	vsnprintf(buffer, sizeof(buffer), message, vargs);
+	va_end(vargs);
+
+	chipmunk_rs_message(condition, file, line, isError, isHardError, buffer);
+}
@@ -0,0 +1,64 @@
+//! Bindings for the functions in chipmunk_private.h that aren't covered by the generated bindings.
+//! They are not part of Chipmunk's public API, so a Windows DLL doesn't export them. The build fails
+//! when the "private-api" feature is used with one, see link_chipmunk() in build.rs.
+
+use super::*;
+
+extern "C-unwind" {
+    pub fn cpArrayPush(arr: *mut cpArray, object: *mut libc::c_void);
+}
+extern "C-unwind" {
+    pub fn cpArrayPop(arr: *mut cpArray) -> *mut libc::c_void;
+}
+
+pub type cpHashSetTransFunc = ::core::option::Option<
+    unsafe extern "C" fn(ptr: *const libc::c_void, data: *mut libc::c_void) -> *mut libc::c_void,
+>;
+pub type cpHashSetIteratorFunc =
+    ::core::option::Option<unsafe extern "C" fn(elt: *mut libc::c_void, data: *mut libc::c_void)>;
+pub type cpHashSetFilterFunc = ::core::option::Option<
+    unsafe extern "C" fn(elt: *mut libc::c_void, data: *mut libc::c_void) -> cpBool,
+>;
+
+extern "C-unwind" {
+    pub fn cpHashSetInsert(
+        set: *mut cpHashSet,
+        hash: cpHashValue,
+        ptr: *const libc::c_void,
+        trans: cpHashSetTransFunc,
+        data: *mut libc::c_void,
+    ) -> *const libc::c_void;
+}
+extern "C-unwind" {
+    pub fn cpHashSetEach(set: *mut cpHashSet, func: cpHashSetIteratorFunc, data: *mut libc::c_void);
+}
+extern "C-unwind" {
+    pub fn cpHashSetFilter(set: *mut cpHashSet, func: cpHashSetFilterFunc, data: *mut libc::c_void);
+}
+
+extern "C-unwind" {
+    pub fn cpSpacePushFreshContactBuffer(space: *mut cpSpace);
+}
+extern "C-unwind" {
+    pub fn cpContactBufferGetArray(space: *mut cpSpace) -> *mut cpContact;
+}
+extern "C-unwind" {
+    pub fn cpSpacePushContacts(space: *mut cpSpace, count: libc::c_int);
+}
+
+/// Hash function used by Chipmunk for pairs of values, such as the shapes of a cached arbiter.
+pub fn CP_HASH_PAIR(a: cpHashValue, b: cpHashValue) -> cpHashValue {
+    const CP_HASH_COEF: cpHashValue = 3344921057;
+    a.wrapping_mul(CP_HASH_COEF) ^ b.wrapping_mul(CP_HASH_COEF)
+}
+
+// chipmunk.h defines cpcalloc() and cpfree() as the C library's allocator, which Chipmunk uses for memory it takes ownership of.
+// Chipmunk is linked into the same binary whenever this module is available, so these resolve to the allocator it uses.
+extern "C-unwind" {
+    #[link_name = "calloc"]
+    pub fn cpcalloc(count: usize, size: usize) -> *mut libc::c_void;
+}
+extern "C-unwind" {
+    #[link_name = "free"]
+    pub fn cpfree(ptr: *mut libc::c_void);
+}
@@ -1,2 +1,5 @@
-#include <chipmunk/chipmunk.h>
-#include <chipmunk/chipmunk_structs.h>
+#include <chipmunk/chipmunk.h>
+#include <chipmunk/chipmunk_structs.h>
+#include <chipmunk/cpPolyline.h>
+#include <chipmunk/cpMarch.h>
+#include <chipmunk/chipmunk_unsafe.h>
@@ -0,0 +1,203 @@
+extern crate chipmunk_sys as sys;
+
+use crate::vect::*;
+use core::fmt;
+
+/// Chipmunk's axis-aligned 2D bounding box type. (left, bottom, right, top)
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct BB(pub sys::cpBB);
+
+impl BB {
+    /// Convenience constructor for cpBB structs.
+    pub fn new(l: Real, b: Real, r: Real, t: Real) -> BB {
+        BB(sys::cpBB { l, b, r, t })
+    }
+
+    /// Constructs a cpBB centered on a point with the given extents (half sizes).
+    pub fn new_for_extents(center: Vect, half_width: Real, half_height: Real) -> BB {
+        BB::new(
+            center.x() - half_width,
+            center.y() - half_height,
+            center.x() + half_width,
+            center.y() + half_height,
+        )
+    }
+
+    /// Constructs a cpBB for a circle with the given position and radius.
+    pub fn new_for_circle(position: Vect, radius: Real) -> BB {
+        BB::new_for_extents(position, radius, radius)
+    }
+
+    pub fn l(&self) -> Real {
+        self.0.l
+    }
+
+    pub fn b(&self) -> Real {
+        self.0.b
+    }
+
+    pub fn r(&self) -> Real {
+        self.0.r
+    }
+
+    pub fn t(&self) -> Real {
+        self.0.t
+    }
+
+    /// Returns true if @c a and @c b intersect.
+    pub fn intersects(self, other: BB) -> bool {
+        self.0.l <= other.0.r
+            && other.0.l <= self.0.r
+            && self.0.b <= other.0.t
+            && other.0.b <= self.0.t
+    }
+
+    /// Returns true if @c other lies completely within @c bb.
+    pub fn contains_bb(self, other: BB) -> bool {
+        self.0.l <= other.0.l
+            && self.0.r >= other.0.r
+            && self.0.b <= other.0.b
+            && self.0.t >= other.0.t
+    }
+
+    /// Returns true if @c bb contains @c v.
+    pub fn contains_vect(self, v: Vect) -> bool {
+        self.0.l <= v.x() && self.0.r >= v.x() && self.0.b <= v.y() && self.0.t >= v.y()
+    }
+
+    /// Returns a bounding box that holds both bounding boxes.
+    pub fn merge(self, other: BB) -> BB {
+        BB::new(
+            self.0.l.min(other.0.l),
+            self.0.b.min(other.0.b),
+            self.0.r.max(other.0.r),
+            self.0.t.max(other.0.t),
+        )
+    }
+
+    /// Returns a bounding box that holds both @c bb and @c v.
+    pub fn expand(self, v: Vect) -> BB {
+        BB::new(
+            self.0.l.min(v.x()),
+            self.0.b.min(v.y()),
+            self.0.r.max(v.x()),
+            self.0.t.max(v.y()),
+        )
+    }
+
+    /// Returns the center of a bounding box.
+    pub fn center(self) -> Vect {
+        Vect::new((self.0.l + self.0.r) * 0.5, (self.0.b + self.0.t) * 0.5)
+    }
+
+    /// Returns the area of the bounding box.
+    pub fn area(self) -> Real {
+        (self.0.r - self.0.l) * (self.0.t - self.0.b)
+    }
+
+    /// Merges @c a and @c b and returns the area of the merged bounding box.
+    pub fn merged_area(self, other: BB) -> Real {
+        self.merge(other).area()
+    }
+
+    /// Returns the fraction along the segment query the cpBB is hit. Returns INFINITY if it doesn't hit.
+    pub fn segment_query(self, a: Vect, b: Vect) -> Real {
+        let delta = b - a;
+        let mut tmin = -Real::INFINITY;
+        let mut tmax = Real::INFINITY;
+
+        if delta.x() == 0.0 {
+            if a.x() < self.0.l || self.0.r < a.x() {
+                return Real::INFINITY;
+            }
+        } else {
+            let t1 = (self.0.l - a.x()) / delta.x();
+            let t2 = (self.0.r - a.x()) / delta.x();
+            tmin = tmin.max(t1.min(t2));
+            tmax = tmax.min(t1.max(t2));
+        }
+
+        if delta.y() == 0.0 {
+            if a.y() < self.0.b || self.0.t < a.y() {
+                return Real::INFINITY;
+            }
+        } else {
+            let t1 = (self.0.b - a.y()) / delta.y();
+            let t2 = (self.0.t - a.y()) / delta.y();
+            tmin = tmin.max(t1.min(t2));
+            tmax = tmax.min(t1.max(t2));
+        }
+
+        if tmin <= tmax && 0.0 <= tmax && tmin <= 1.0 {
+            tmin.max(0.0)
+        } else {
+            Real::INFINITY
+        }
+    }
+
+    /// Return true if the bounding box intersects the line segment with ends @c a and @c b.
+    pub fn intersects_segment(self, a: Vect, b: Vect) -> bool {
+        self.segment_query(a, b) != Real::INFINITY
+    }
+
+    /// Clamp a vector to a bounding box.
+    pub fn clamp_vect(self, v: Vect) -> Vect {
+        Vect::new(
+            v.x().max(self.0.l).min(self.0.r),
+            v.y().max(self.0.b).min(self.0.t),
+        )
+    }
+
+    /// Wrap a vector to a bounding box.
+    pub fn wrap_vect(self, v: Vect) -> Vect {
+        let dx = (self.0.r - self.0.l).abs();
+        let modx = (v.x() - self.0.l) % dx;
+        let x = if modx > 0.0 { modx } else { modx + dx };
+
+        let dy = (self.0.t - self.0.b).abs();
+        let mody = (v.y() - self.0.b) % dy;
+        let y = if mody > 0.0 { mody } else { mody + dy };
+
+        Vect::new(x + self.0.l, y + self.0.b)
+    }
+
+    /// Returns a bounding box offseted by @c v.
+    pub fn offset(self, v: Vect) -> BB {
+        BB::new(
+            self.0.l + v.x(),
+            self.0.b + v.y(),
+            self.0.r + v.x(),
+            self.0.t + v.y(),
+        )
+    }
+}
+
+impl Default for BB {
+    fn default() -> BB {
+        BB::new(0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl From<sys::cpBB> for BB {
+    fn from(bb: sys::cpBB) -> Self {
+        BB(bb)
+    }
+}
+
+impl From<BB> for sys::cpBB {
+    fn from(bb: BB) -> Self {
+        bb.0
+    }
+}
+
+impl fmt::Debug for BB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpBB")
+            .field("l", &self.0.l)
+            .field("b", &self.0.b)
+            .field("r", &self.0.r)
+            .field("t", &self.0.t)
+            .finish()
+    }
+}
@@ -1,24 +1,84 @@
 extern crate chipmunk_sys as sys;
 
+use crate::error::*;
+use crate::shape_filter::*;
+use crate::space::*;
 use crate::vect::*;
-use std::ffi;
+use crate::version::*;
+use crate::Shape;
+use alloc::vec::Vec;
+use core::ffi;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr;
+
+/// The ways a body can be simulated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BodyType {
+    /// A dynamic body is one that is affected by gravity, forces, and collisions.
+    /// This is the default body type.
+    Dynamic,
+    /// A kinematic body is an infinite mass, user controlled body that is not affected by gravity, forces or collisions.
+    /// Instead the body only moves based on it's velocity.
+    /// Dynamic bodies collide normally with kinematic bodies, though the kinematic body will be unaffected.
+    /// Collisions between two kinematic bodies, or a kinematic body and a static body produce collision callbacks, but no collision response.
+    Kinematic,
+    /// A static body is a body that never (or rarely) moves. If you move a static body, you must call one of the cpSpaceReindex*() functions.
+    /// Chipmunk uses this information to optimize the collision detection.
+    /// Static bodies do not produce collision callbacks when colliding with other static bodies.
+    Static,
+}
+
+impl From<sys::cpBodyType> for BodyType {
+    fn from(body_type: sys::cpBodyType) -> Self {
+        match body_type {
+            sys::cpBodyType::CP_BODY_TYPE_DYNAMIC => BodyType::Dynamic,
+            sys::cpBodyType::CP_BODY_TYPE_KINEMATIC => BodyType::Kinematic,
+            sys::cpBodyType::CP_BODY_TYPE_STATIC => BodyType::Static,
+        }
+    }
+}
+
+impl From<BodyType> for sys::cpBodyType {
+    fn from(body_type: BodyType) -> Self {
+        match body_type {
+            BodyType::Dynamic => sys::cpBodyType::CP_BODY_TYPE_DYNAMIC,
+            BodyType::Kinematic => sys::cpBodyType::CP_BODY_TYPE_KINEMATIC,
+            BodyType::Static => sys::cpBodyType::CP_BODY_TYPE_STATIC,
+        }
+    }
+}
 
 pub struct Body(pub *mut sys::cpBody, pub bool);
 
 impl Body {
     pub unsafe fn null() -> Body {
-        Body(std::ptr::null_mut(), false)
+        Body(core::ptr::null_mut(), false)
     }
 
-    pub fn new(mass: f64, moment: f64) -> Body {
+    pub fn new(mass: Real, moment: Real) -> Body {
+        assert_version();
         Body(unsafe { sys::cpBodyNew(mass, moment) }, true)
     }
 
+    /// Like new(), but returns an error if the mass is negative or not finite, or the moment is negative or NaN,
+    /// or if check_version() fails.
+    pub fn try_new(mass: Real, moment: Real) -> Result<Body, ChipmunkError> {
+        check_version()?;
+        check_mass(mass)?;
+        check_non_negative("moment", moment)?;
+        Ok(Body::new(mass, moment))
+    }
+
     pub fn new_kinematic() -> Body {
+        assert_version();
         Body(unsafe { sys::cpBodyNewKinematic() }, true)
     }
 
     pub fn new_static() -> Body {
+        assert_version();
         Body(unsafe { sys::cpBodyNewStatic() }, true)
     }
 
@@ -28,49 +88,178 @@ impl Body {
     }
 
     /// Wake up any sleeping or idle bodies touching a static body.
-    // CP_EXPORT void cpBodyActivateStatic(cpBody *body, cpShape *filter);
+    /// Only bodies touching the static body with a shape that passes @c filter are woken.
+    /// Use ShapeFilter::ALL to wake everything resting on the body, for instance before removing it.
+    pub fn activate_static(&mut self, filter: ShapeFilter) {
+        extern "C" fn arbiter_func(
+            _body: *mut sys::cpBody,
+            arbiter: *mut sys::cpArbiter,
+            user_data: *mut c_void,
+        ) {
+            let touching = user_data as *mut (ShapeFilter, Vec<*mut sys::cpBody>);
+            unsafe {
+                let (mut shape, mut other_shape) = (ptr::null_mut(), ptr::null_mut());
+                sys::cpArbiterGetShapes(arbiter, &mut shape, &mut other_shape);
+                let (mut body, mut other) = (ptr::null_mut(), ptr::null_mut());
+                sys::cpArbiterGetBodies(arbiter, &mut body, &mut other);
+
+                let other_filter = ShapeFilter(sys::cpShapeGetFilter(other_shape));
+                if !(*touching).0.reject(other_filter) {
+                    (*touching).1.push(other);
+                }
+            }
+        }
+
+        assert!(self.is_static(), "Body is not static.");
+
+        let mut touching = (filter, Vec::new());
+        unsafe {
+            sys::cpBodyEachArbiter(
+                self.0,
+                Some(arbiter_func),
+                &mut touching as *mut _ as *mut c_void,
+            );
+            for other in touching.1 {
+                sys::cpBodyActivate(other);
+            }
+        }
+    }
+
+    /// Wake up any sleeping or idle bodies touching @c shape, which must be attached to this static body.
+    pub fn activate_static_for_shape(&mut self, shape: &Shape) {
+        assert!(self.is_static(), "Body is not static.");
+        unsafe { sys::cpBodyActivateStatic(self.0, shape.0) };
+    }
 
     /// Force a body to fall asleep immediately.
+    /// Panics if the body can't be put to sleep, see try_sleep_with_group().
     pub fn sleep(&mut self) {
-        unsafe { sys::cpBodySleep(self.0) };
+        self.sleep_with_group(None);
     }
 
     /// Force a body to fall asleep immediately along with other bodies in a group.
-    // CP_EXPORT void cpBodySleepWithGroup(cpBody *body, cpBody *group);
+    /// Pass None to start a new group, then pass that body to put further bodies to sleep in the same group.
+    /// The group will wake up together once any of its bodies is touched or activated.
+    /// Panics if the body can't be put to sleep, see try_sleep_with_group().
+    pub fn sleep_with_group(&mut self, group: Option<&Body>) {
+        if let Err(err) = self.try_sleep_with_group(group) {
+            panic!("Cannot put the body to sleep: {}.", err);
+        }
+    }
+
+    /// Like sleep(), but returns an error instead of panicking if the body can't be put to sleep.
+    pub fn try_sleep(&mut self) -> Result<(), ChipmunkError> {
+        self.try_sleep_with_group(None)
+    }
+
+    /// Like sleep_with_group(), but returns an error instead of panicking if the body can't be put to sleep.
+    /// The body must be dynamic and added to a space that is not locked and has a finite sleep time threshold.
+    /// @c group must be sleeping, and a body that is already sleeping can't be moved to another group.
+    pub fn try_sleep_with_group(&mut self, group: Option<&Body>) -> Result<(), ChipmunkError> {
+        self.check_dynamic()?;
+        let space = unsafe { sys::cpBodyGetSpace(self.0) };
+        if space.is_null() {
+            return Err(ChipmunkError::NotInSpace);
+        }
+        unsafe {
+            if sys::cpSpaceIsLocked(space) != 0 {
+                return Err(ChipmunkError::SpaceLocked);
+            }
+            let threshold = sys::cpSpaceGetSleepTimeThreshold(space);
+            if threshold.is_nan() || threshold == Real::INFINITY {
+                return Err(ChipmunkError::SleepingDisabled);
+            }
+        }
+
+        let group = group.map_or(ptr::null_mut(), |group| group.0);
+        unsafe {
+            if !group.is_null() && sys::cpBodyIsSleeping(group) == 0 {
+                return Err(ChipmunkError::InvalidSleepGroup);
+            }
+            // a sleeping body can only be put to sleep again in its own group, see cpBodySleepWithGroup()
+            if sys::cpBodyIsSleeping(self.0) != 0 {
+                let group_root = if group.is_null() {
+                    ptr::null_mut()
+                } else {
+                    (*group).sleeping.root
+                };
+                if (*self.0).sleeping.root != group_root {
+                    return Err(ChipmunkError::InvalidSleepGroup);
+                }
+            }
+
+            sys::cpBodySleepWithGroup(self.0, group);
+        }
+        Ok(())
+    }
 
     /// Returns true if the body is sleeping.
     pub fn is_sleeping(&mut self) -> bool {
         unsafe { sys::cpBodyIsSleeping(self.0) != 0 }
     }
 
+    fn check_dynamic(&self) -> Result<(), ChipmunkError> {
+        match unsafe { sys::cpBodyGetType(self.0) } {
+            sys::cpBodyType::CP_BODY_TYPE_DYNAMIC => Ok(()),
+            _ => Err(ChipmunkError::NotDynamic),
+        }
+    }
+
+    fn is_static(&self) -> bool {
+        unsafe { sys::cpBodyGetType(self.0) == sys::cpBodyType::CP_BODY_TYPE_STATIC }
+    }
+
     /// Get the type of the body.
-    // CP_EXPORT cpBodyType cpBodyGetType(cpBody *body);
+    pub fn body_type(&self) -> BodyType {
+        unsafe { sys::cpBodyGetType(self.0) }.into()
+    }
+
     /// Set the type of the body.
-    // CP_EXPORT void cpBodySetType(cpBody *body, cpBodyType type);
+    pub fn set_body_type(&mut self, body_type: BodyType) {
+        unsafe { sys::cpBodySetType(self.0, body_type.into()) }
+    }
 
-    /// Get the space this body is added to.
-    // CP_EXPORT cpSpace* cpBodyGetSpace(const cpBody *body);
+    /// Get the space this body is added to, if any.
+    pub fn space(&self) -> Option<SpaceRef<'_>> {
+        unsafe { SpaceRef::from_raw(sys::cpBodyGetSpace(self.0)) }
+    }
 
     /// Get the mass of the body.
-    pub fn mass(&self) -> f64 {
+    pub fn mass(&self) -> Real {
         unsafe { sys::cpBodyGetMass(self.0) }
     }
 
     /// Set the mass of the body.
-    pub fn set_mass(&mut self, mass: f64) {
+    pub fn set_mass(&mut self, mass: Real) {
         unsafe { sys::cpBodySetMass(self.0, mass) }
     }
 
+    /// Like set_mass(), but returns an error if the body is not dynamic or the mass is negative or not finite.
+    pub fn try_set_mass(&mut self, mass: Real) -> Result<(), ChipmunkError> {
+        self.check_dynamic()?;
+        check_mass(mass)?;
+        self.set_mass(mass);
+        Ok(())
+    }
+
     /// Get the moment of inertia of the body.
-    pub fn moment(&self) -> f64 {
+    pub fn moment(&self) -> Real {
         unsafe { sys::cpBodyGetMoment(self.0) }
     }
 
     /// Set the moment of inertia of the body.
-    pub fn set_moment(&mut self, moment: f64) {
+    pub fn set_moment(&mut self, moment: Real) {
         unsafe { sys::cpBodySetMoment(self.0, moment) }
     }
 
+    /// Like set_moment(), but returns an error if the body is not dynamic or the moment is negative or NaN.
+    pub fn try_set_moment(&mut self, moment: Real) -> Result<(), ChipmunkError> {
+        self.check_dynamic()?;
+        check_non_negative("moment", moment)?;
+        self.set_moment(moment);
+        Ok(())
+    }
+
     /// Set the position of a body.
     pub fn position(&self) -> Vect {
         unsafe { sys::cpBodyGetPosition(self.0) }.into()
@@ -81,6 +270,13 @@ impl Body {
         unsafe { sys::cpBodySetPosition(self.0, position.0) }
     }
 
+    /// Like set_position(), but returns an error if the position is not finite.
+    pub fn try_set_position(&mut self, position: Vect) -> Result<(), ChipmunkError> {
+        check_finite_vect("position", position)?;
+        self.set_position(position);
+        Ok(())
+    }
+
     /// Get the offset of the center of gravity in body local coordinates.
     pub fn center_of_gravity(&self) -> Vect {
         unsafe { sys::cpBodyGetCenterOfGravity(self.0) }.into()
@@ -101,6 +297,13 @@ impl Body {
         unsafe { sys::cpBodySetVelocity(self.0, velocity.0) }
     }
 
+    /// Like set_velocity(), but returns an error if the velocity is not finite.
+    pub fn try_set_velocity(&mut self, velocity: Vect) -> Result<(), ChipmunkError> {
+        check_finite_vect("velocity", velocity)?;
+        self.set_velocity(velocity);
+        Ok(())
+    }
+
     /// Get the force applied to the body for the next time step.
     pub fn force(&self) -> Vect {
         unsafe { sys::cpBodyGetForce(self.0) }.into()
@@ -112,32 +315,39 @@ impl Body {
     }
 
     /// Get the angle of the body.
-    pub fn angle(&self) -> f64 {
+    pub fn angle(&self) -> Real {
         unsafe { sys::cpBodyGetAngle(self.0) }
     }
 
     /// Set the angle of a body.
-    pub fn set_angle(&mut self, angle: f64) {
+    pub fn set_angle(&mut self, angle: Real) {
         unsafe { sys::cpBodySetAngle(self.0, angle) }
     }
 
+    /// Like set_angle(), but returns an error if the angle is not finite.
+    pub fn try_set_angle(&mut self, angle: Real) -> Result<(), ChipmunkError> {
+        check_finite("angle", angle)?;
+        self.set_angle(angle);
+        Ok(())
+    }
+
     /// Get the angular velocity of the body.
-    pub fn angular_velocity(&self) -> f64 {
+    pub fn angular_velocity(&self) -> Real {
         unsafe { sys::cpBodyGetAngularVelocity(self.0) }
     }
 
     /// Set the angular velocity of the body.
-    pub fn set_angular_velocity(&mut self, angular_velocity: f64) {
+    pub fn set_angular_velocity(&mut self, angular_velocity: Real) {
         unsafe { sys::cpBodySetAngularVelocity(self.0, angular_velocity) }
     }
 
     /// Get the torque applied to the body for the next time step.
-    pub fn torque(&self) -> f64 {
+    pub fn torque(&self) -> Real {
         unsafe { sys::cpBodyGetTorque(self.0) }
     }
 
     /// Set the torque applied to the body for the next time step.
-    pub fn set_torque(&mut self, torque: f64) {
+    pub fn set_torque(&mut self, torque: Real) {
         unsafe { sys::cpBodySetTorque(self.0, torque) }
     }
 
@@ -208,9 +418,14 @@ impl Body {
 
 unsafe impl Send for Body {}
 
+fn check_mass(mass: Real) -> Result<(), ChipmunkError> {
+    check_finite("mass", mass)?;
+    check_non_negative("mass", mass)
+}
+
 impl Default for Body {
     fn default() -> Body {
-        Body(std::ptr::null_mut(), false)
+        Body(core::ptr::null_mut(), false)
     }
 }
 
@@ -221,3 +436,26 @@ impl Drop for Body {
         }
     }
 }
+
+/// A borrowed view of a body owned elsewhere, such as the body a shape is attached to.
+/// Dropping the view never frees the body.
+pub struct BodyRef<'a>(Body, PhantomData<&'a Body>);
+
+impl<'a> BodyRef<'a> {
+    /// Wrap a body pointer without taking ownership of it. Returns None if the pointer is null.
+    pub(crate) unsafe fn from_raw(body: *mut sys::cpBody) -> Option<BodyRef<'a>> {
+        if body.is_null() {
+            None
+        } else {
+            Some(BodyRef(Body(body, false), PhantomData))
+        }
+    }
+}
+
+impl<'a> Deref for BodyRef<'a> {
+    type Target = Body;
+
+    fn deref(&self) -> &Body {
+        &self.0
+    }
+}
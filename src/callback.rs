@@ -0,0 +1,64 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::any::Any;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+
+/// A closure passed to Chipmunk as the user data of a callback.
+/// A panic can't unwind through Chipmunk's functions, so with the "std" feature the closure's panic is caught,
+/// it isn't called again, and finish() resumes the panic once the Chipmunk function has returned.
+/// Without the "std" feature, a panic aborts the process.
+pub(crate) struct Callback<F> {
+    func: F,
+    #[cfg(feature = "std")]
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<F> Callback<F> {
+    pub fn new(func: F) -> Callback<F> {
+        Callback {
+            func,
+            #[cfg(feature = "std")]
+            panic: None,
+        }
+    }
+
+    /// Call the closure through @c call, or return @c default if it panicked.
+    pub fn call<R, C>(&mut self, default: R, call: C) -> R
+    where
+        C: FnOnce(&mut F) -> R,
+    {
+        #[cfg(feature = "std")]
+        {
+            if self.panic.is_some() {
+                return default;
+            }
+
+            let func = &mut self.func;
+            match panic::catch_unwind(AssertUnwindSafe(|| call(func))) {
+                Ok(result) => result,
+                Err(payload) => {
+                    self.panic = Some(payload);
+                    default
+                }
+            }
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = default;
+            call(&mut self.func)
+        }
+    }
+
+    /// Resume the closure's panic, if it panicked.
+    pub fn finish(self) {
+        #[cfg(feature = "std")]
+        {
+            if let Some(payload) = self.panic {
+                panic::resume_unwind(payload);
+            }
+        }
+    }
+}
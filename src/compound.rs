@@ -0,0 +1,244 @@
+use crate::math::*;
+use crate::polyline::*;
+use crate::vect::*;
+use crate::{Body, PolyError, Shape, Transform};
+use alloc::vec::Vec;
+
+/// A body made up of several convex polygon shapes.
+pub struct CompoundBody {
+    pub body: Body,
+    pub shapes: Vec<Shape>,
+}
+
+/// Builds a compound body from an arbitrary simple polygon, optionally with holes.
+/// The polygon is split into convex pieces with Polyline::convex_decomposition()
+/// and one poly shape is created for each piece.
+pub struct CompoundBodyBuilder {
+    outline: Vec<Vect>,
+    holes: Vec<Vec<Vect>>,
+    density: Real,
+    radius: Real,
+    tolerance: Real,
+}
+
+impl CompoundBodyBuilder {
+    /// Start building from the outline of a simple polygon. Either winding may be used.
+    pub fn new(outline: &[Vect]) -> CompoundBodyBuilder {
+        CompoundBodyBuilder {
+            outline: outline.to_vec(),
+            holes: Vec::new(),
+            density: 1.0,
+            radius: 0.0,
+            tolerance: 0.0,
+        }
+    }
+
+    /// Cut a hole out of the polygon. The hole must lie inside the outline and not overlap other holes.
+    pub fn hole(mut self, hole: &[Vect]) -> CompoundBodyBuilder {
+        self.holes.push(hole.to_vec());
+        self
+    }
+
+    /// Density shared by every piece, used to derive the body's mass and moment.
+    pub fn density(mut self, density: Real) -> CompoundBodyBuilder {
+        self.density = density;
+        self
+    }
+
+    /// Radius of the rounded corners of each piece.
+    pub fn radius(mut self, radius: Real) -> CompoundBodyBuilder {
+        self.radius = radius;
+        self
+    }
+
+    /// How far the convex pieces may deviate from the original polygon.
+    pub fn tolerance(mut self, tolerance: Real) -> CompoundBodyBuilder {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Split the polygon into convex pieces, each a looped list of vertexes with a counter-clockwise winding.
+    pub fn pieces(&self) -> Result<Vec<Vec<Vect>>, PolyError> {
+        let rings = core::iter::once(&self.outline).chain(self.holes.iter());
+        for ring in rings {
+            if ring
+                .iter()
+                .any(|v| !v.x().is_finite() || !v.y().is_finite())
+            {
+                return Err(PolyError::NonFinite);
+            }
+        }
+
+        let mut outline = open_ring(&self.outline, true);
+        if outline.is_empty() {
+            return Err(PolyError::Empty);
+        }
+        if outline.len() < 3 {
+            return Err(PolyError::Degenerate);
+        }
+
+        let mut holes: Vec<Vec<Vect>> = self
+            .holes
+            .iter()
+            .map(|hole| open_ring(hole, false))
+            .filter(|hole| hole.len() >= 3)
+            .collect();
+
+        // Merge the holes into the outline, rightmost first, using a pair of coincident bridge edges.
+        holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap());
+        while !holes.is_empty() {
+            let hole = holes.remove(0);
+            outline = bridge_hole(&outline, &hole, &holes)?;
+        }
+
+        outline.push(outline[0]);
+        let pieces = Polyline(outline).convex_decomposition(self.tolerance);
+        Ok(pieces.0.into_iter().map(|line| line.0).collect())
+    }
+
+    /// The convex pieces without their closing vertexes, skipping any slivers left by hole bridges.
+    fn open_pieces(&self) -> Result<Vec<Vec<Vect>>, PolyError> {
+        Ok(self
+            .pieces()?
+            .into_iter()
+            .map(|mut piece| {
+                piece.pop();
+                piece
+            })
+            .filter(|piece| area_for_poly(piece, self.radius) > 0.0)
+            .collect())
+    }
+
+    /// Build a new dynamic body with one poly shape per convex piece.
+    /// The body's mass, moment and center of gravity are derived from the pieces and the shared density,
+    /// so the shapes keep the coordinates of the original polygon.
+    pub fn build(&self) -> Result<CompoundBody, PolyError> {
+        let pieces = self.open_pieces()?;
+
+        let masses: Vec<Real> = pieces
+            .iter()
+            .map(|piece| self.density * area_for_poly(piece, self.radius))
+            .collect();
+        let mass: Real = masses.iter().sum();
+        if mass.is_nan() || mass <= 0.0 {
+            return Err(PolyError::Degenerate);
+        }
+
+        let center_of_gravity = pieces
+            .iter()
+            .zip(masses.iter())
+            .fold(Vect::zero(), |sum, (piece, &m)| {
+                sum + centroid_for_poly(piece) * m
+            })
+            * (1.0 / mass);
+        let moment = pieces
+            .iter()
+            .zip(masses.iter())
+            .map(|(piece, &m)| moment_for_poly(m, piece, -center_of_gravity, self.radius))
+            .sum();
+
+        let mut body = Body::new(mass, moment);
+        let shapes = self.create_shapes(&body, &pieces)?;
+
+        // Setting the shape densities recalculates the body's mass from its shapes in the space,
+        // so apply the combined values afterwards.
+        body.set_mass(mass);
+        body.set_moment(moment);
+        body.set_center_of_gravity(center_of_gravity);
+
+        Ok(CompoundBody { body, shapes })
+    }
+
+    /// Attach one poly shape per convex piece to an existing body, such as a static body.
+    /// The body's mass properties are left for Chipmunk to accumulate from the shape densities.
+    pub fn attach(&self, body: &Body) -> Result<Vec<Shape>, PolyError> {
+        let pieces = self.open_pieces()?;
+
+        self.create_shapes(body, &pieces)
+    }
+
+    fn create_shapes(&self, body: &Body, pieces: &[Vec<Vect>]) -> Result<Vec<Shape>, PolyError> {
+        pieces
+            .iter()
+            .map(|piece| {
+                let mut shape = Shape::poly_with_transform(
+                    body,
+                    piece,
+                    Transform::identity(),
+                    self.radius,
+                    0.0,
+                )?;
+                shape.set_density(self.density);
+                Ok(shape)
+            })
+            .collect()
+    }
+}
+
+/// Remove the closing vertex of a looped ring and give it the requested winding.
+fn open_ring(verts: &[Vect], counter_clockwise: bool) -> Vec<Vect> {
+    let mut ring = verts.to_vec();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    if (area_for_poly(&ring, 0.0) > 0.0) != counter_clockwise {
+        ring.reverse();
+    }
+    ring
+}
+
+fn max_x(ring: &[Vect]) -> Real {
+    ring.iter().fold(-Real::INFINITY, |x, v| x.max(v.x()))
+}
+
+/// Returns true if segments (a, b) and (c, d) cross at a point that is not one of their endpoints.
+fn segments_cross(a: Vect, b: Vect, c: Vect, d: Vect) -> bool {
+    let d1 = (b - a).cross(c - a);
+    let d2 = (b - a).cross(d - a);
+    let d3 = (d - c).cross(a - c);
+    let d4 = (d - c).cross(b - c);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+fn ring_crosses(ring: &[Vect], a: Vect, b: Vect) -> bool {
+    (0..ring.len()).any(|i| segments_cross(a, b, ring[i], ring[(i + 1) % ring.len()]))
+}
+
+/// Join a clockwise hole to a counter-clockwise outline by cutting a bridge from the hole's
+/// rightmost vertex to the nearest outline vertex it can see.
+fn bridge_hole(
+    outline: &[Vect],
+    hole: &[Vect],
+    others: &[Vec<Vect>],
+) -> Result<Vec<Vect>, PolyError> {
+    let (j, h) = hole
+        .iter()
+        .cloned()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.x().partial_cmp(&b.x()).unwrap())
+        .unwrap();
+
+    let mut candidates: Vec<usize> = (0..outline.len()).collect();
+    candidates.sort_by(|&a, &b| {
+        let da = (outline[a] - h).length_squared();
+        let db = (outline[b] - h).length_squared();
+        da.partial_cmp(&db).unwrap()
+    });
+
+    let i = candidates
+        .into_iter()
+        .find(|&i| {
+            let o = outline[i];
+            !ring_crosses(outline, h, o)
+                && !ring_crosses(hole, h, o)
+                && !others.iter().any(|other| ring_crosses(other, h, o))
+        })
+        .ok_or(PolyError::Degenerate)?;
+
+    let mut merged = Vec::with_capacity(outline.len() + hole.len() + 2);
+    merged.extend_from_slice(&outline[..=i]);
+    merged.extend_from_slice(&hole[j..]);
+    merged.extend_from_slice(&hole[..=j]);
+    merged.extend_from_slice(&outline[i..]);
+    Ok(merged)
+}
@@ -0,0 +1,108 @@
+extern crate chipmunk_sys as sys;
+
+use crate::body::*;
+use crate::space::*;
+use crate::vect::*;
+
+/// A joint or motor connecting two bodies.
+/// Use ConstraintDef to create one.
+pub struct Constraint(pub *mut sys::cpConstraint, pub bool);
+
+impl Constraint {
+    /// Get the space this constraint is added to, if any.
+    pub fn space(&self) -> Option<SpaceRef<'_>> {
+        unsafe { SpaceRef::from_raw(sys::cpConstraintGetSpace(self.0)) }
+    }
+
+    /// Get the first body the constraint is attached to.
+    pub fn body_a(&self) -> Option<BodyRef<'_>> {
+        unsafe { BodyRef::from_raw(sys::cpConstraintGetBodyA(self.0)) }
+    }
+
+    /// Get the second body the constraint is attached to.
+    pub fn body_b(&self) -> Option<BodyRef<'_>> {
+        unsafe { BodyRef::from_raw(sys::cpConstraintGetBodyB(self.0)) }
+    }
+
+    /// Get the maximum force that this constraint is allowed to use.
+    pub fn max_force(&self) -> Real {
+        unsafe { sys::cpConstraintGetMaxForce(self.0) }
+    }
+
+    /// Set the maximum force that this constraint is allowed to use. (defaults to INFINITY)
+    pub fn set_max_force(&mut self, max_force: Real) {
+        unsafe { sys::cpConstraintSetMaxForce(self.0, max_force) }
+    }
+
+    /// Get rate at which joint error is corrected.
+    pub fn error_bias(&self) -> Real {
+        unsafe { sys::cpConstraintGetErrorBias(self.0) }
+    }
+
+    /// Set rate at which joint error is corrected.
+    /// Defaults to pow(1.0 - 0.1, 60.0) meaning that it will
+    /// correct 10% of the error every 1/60th of a second.
+    pub fn set_error_bias(&mut self, error_bias: Real) {
+        unsafe { sys::cpConstraintSetErrorBias(self.0, error_bias) }
+    }
+
+    /// Get the maximum rate at which joint error is corrected.
+    pub fn max_bias(&self) -> Real {
+        unsafe { sys::cpConstraintGetMaxBias(self.0) }
+    }
+
+    /// Set the maximum rate at which joint error is corrected. (defaults to INFINITY)
+    pub fn set_max_bias(&mut self, max_bias: Real) {
+        unsafe { sys::cpConstraintSetMaxBias(self.0, max_bias) }
+    }
+
+    /// Get if the two bodies connected by the constraint are allowed to collide or not.
+    pub fn collide_bodies(&self) -> bool {
+        unsafe { sys::cpConstraintGetCollideBodies(self.0) != 0 }
+    }
+
+    /// Set if the two bodies connected by the constraint are allowed to collide or not. (defaults to true)
+    pub fn set_collide_bodies(&mut self, collide_bodies: bool) {
+        unsafe { sys::cpConstraintSetCollideBodies(self.0, collide_bodies as u8) }
+    }
+
+    /// Get the last impulse applied by this constraint.
+    pub fn impulse(&self) -> Real {
+        unsafe { sys::cpConstraintGetImpulse(self.0) }
+    }
+}
+
+unsafe impl Send for Constraint {}
+
+impl Default for Constraint {
+    fn default() -> Constraint {
+        Constraint(core::ptr::null_mut(), false)
+    }
+}
+
+impl Drop for Constraint {
+    fn drop(&mut self) {
+        if self.1 {
+            unsafe { sys::cpConstraintFree(self.0) };
+        }
+    }
+}
+
+/// Call @c func for each constraint attached to @c body that is added to a space, as cpSpaceAddConstraint() is what
+/// links a constraint into the lists of its bodies. Unlike cpSpaceEachConstraint(), this also finds the constraints of sleeping bodies.
+pub(crate) unsafe fn each_body_constraint<F>(body: *mut sys::cpBody, mut func: F)
+where
+    F: FnMut(*mut sys::cpConstraint),
+{
+    let mut constraint = (*body).constraintList;
+    while !constraint.is_null() {
+        // the list is threaded through both bodies of each constraint, see cpConstraintNext()
+        let next = if (*constraint).a == body {
+            (*constraint).next_a
+        } else {
+            (*constraint).next_b
+        };
+        func(constraint);
+        constraint = next;
+    }
+}
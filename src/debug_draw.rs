@@ -0,0 +1,307 @@
+extern crate chipmunk_sys as sys;
+
+use crate::vect::*;
+use crate::Shape;
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+use core::ops::{BitAnd, BitOr};
+
+/// Color type to use with the space debug drawing API.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DebugColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl DebugColor {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> DebugColor {
+        DebugColor { r, g, b, a }
+    }
+
+    /// A grey color with the given luminance and alpha.
+    pub fn gray(l: f32, a: f32) -> DebugColor {
+        DebugColor::new(l, l, l, a)
+    }
+}
+
+impl From<sys::cpSpaceDebugColor> for DebugColor {
+    fn from(c: sys::cpSpaceDebugColor) -> Self {
+        DebugColor::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<DebugColor> for sys::cpSpaceDebugColor {
+    fn from(c: DebugColor) -> Self {
+        sys::cpSpaceDebugColor {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: c.a,
+        }
+    }
+}
+
+/// Flags that request which things to draw (collision shapes, constraints, contact points).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DebugDrawFlags(pub u32);
+
+impl DebugDrawFlags {
+    pub const NONE: DebugDrawFlags = DebugDrawFlags(0);
+    pub const SHAPES: DebugDrawFlags =
+        DebugDrawFlags(sys::cpSpaceDebugDrawFlags::CP_SPACE_DEBUG_DRAW_SHAPES as u32);
+    pub const CONSTRAINTS: DebugDrawFlags =
+        DebugDrawFlags(sys::cpSpaceDebugDrawFlags::CP_SPACE_DEBUG_DRAW_CONSTRAINTS as u32);
+    pub const COLLISION_POINTS: DebugDrawFlags =
+        DebugDrawFlags(sys::cpSpaceDebugDrawFlags::CP_SPACE_DEBUG_DRAW_COLLISION_POINTS as u32);
+    pub const ALL: DebugDrawFlags = DebugDrawFlags(
+        DebugDrawFlags::SHAPES.0
+            | DebugDrawFlags::CONSTRAINTS.0
+            | DebugDrawFlags::COLLISION_POINTS.0,
+    );
+
+    /// Returns true if all of the flags in @c other are set.
+    pub fn contains(self, other: DebugDrawFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for DebugDrawFlags {
+    fn default() -> DebugDrawFlags {
+        DebugDrawFlags::ALL
+    }
+}
+
+impl BitOr for DebugDrawFlags {
+    type Output = DebugDrawFlags;
+
+    fn bitor(self, rhs: DebugDrawFlags) -> DebugDrawFlags {
+        DebugDrawFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for DebugDrawFlags {
+    type Output = DebugDrawFlags;
+
+    fn bitand(self, rhs: DebugDrawFlags) -> DebugDrawFlags {
+        DebugDrawFlags(self.0 & rhs.0)
+    }
+}
+
+/// Drawing callbacks invoked by Space::debug_draw().
+pub trait DebugDraw {
+    /// Draw a circle.
+    fn draw_circle(
+        &mut self,
+        position: Vect,
+        angle: Real,
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    );
+
+    /// Draw a line segment.
+    fn draw_segment(&mut self, a: Vect, b: Vect, color: DebugColor);
+
+    /// Draw a thick line segment.
+    fn draw_fat_segment(
+        &mut self,
+        a: Vect,
+        b: Vect,
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    );
+
+    /// Draw a convex polygon.
+    fn draw_polygon(
+        &mut self,
+        verts: &[Vect],
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    );
+
+    /// Draw a dot.
+    fn draw_dot(&mut self, size: Real, position: Vect, color: DebugColor);
+
+    /// Decide what fill color to draw shapes using.
+    /// This gives you an opportunity to color shapes based on how they are used in your engine.
+    fn color_for_shape(&mut self, shape: &Shape) -> DebugColor {
+        default_color_for_shape(shape)
+    }
+
+    /// Outline color passed to the drawing functions for shapes.
+    fn shape_outline_color(&self) -> DebugColor {
+        DebugColor::new(0.784, 0.824, 0.902, 1.0)
+    }
+
+    /// Color passed to drawing functions for constraints.
+    fn constraint_color(&self) -> DebugColor {
+        DebugColor::new(0.0, 0.75, 0.0, 1.0)
+    }
+
+    /// Color passed to drawing functions for collision points.
+    fn collision_point_color(&self) -> DebugColor {
+        DebugColor::new(1.0, 0.0, 0.0, 1.0)
+    }
+}
+
+/// The shape coloring used by the Chipmunk demos.
+/// Sensors are translucent, sleeping and static bodies are grey, and everything else picks
+/// a color from a small palette based on the shape's hash id.
+pub fn default_color_for_shape(shape: &Shape) -> DebugColor {
+    const PALETTE: [(f32, f32, f32); 8] = [
+        (0.710, 0.537, 0.000),
+        (0.796, 0.294, 0.086),
+        (0.863, 0.196, 0.184),
+        (0.827, 0.212, 0.510),
+        (0.424, 0.443, 0.769),
+        (0.149, 0.545, 0.824),
+        (0.165, 0.631, 0.596),
+        (0.522, 0.600, 0.000),
+    ];
+
+    unsafe {
+        if (*shape.0).sensor != 0 {
+            return DebugColor::gray(1.0, 0.1);
+        }
+
+        let body = (*shape.0).body;
+        if sys::cpBodyIsSleeping(body) != 0 {
+            return DebugColor::new(0.345, 0.431, 0.459, 1.0);
+        }
+
+        let space = (*body).space;
+        if !space.is_null() && (*body).sleeping.idleTime > (*space).sleepTimeThreshold {
+            return DebugColor::new(0.576, 0.631, 0.631, 1.0);
+        }
+
+        let mut val = (*shape.0).hashid as u32;
+
+        // scramble the bits up using Robert Jenkins' 32 bit integer hash function
+        val = val.wrapping_add(0x7ed55d16).wrapping_add(val << 12);
+        val = (val ^ 0xc761c23c) ^ (val >> 19);
+        val = val.wrapping_add(0x165667b1).wrapping_add(val << 5);
+        val = val.wrapping_add(0xd3a2646c) ^ (val << 9);
+        val = val.wrapping_add(0xfd7046c5).wrapping_add(val << 3);
+        val = (val ^ 0xb55a4f09) ^ (val >> 16);
+
+        let (r, g, b) = PALETTE[(val & 0x7) as usize];
+        DebugColor::new(r, g, b, 1.0)
+    }
+}
+
+extern "C" fn draw_circle<D: DebugDraw>(
+    position: sys::cpVect,
+    angle: sys::cpFloat,
+    radius: sys::cpFloat,
+    outline_color: sys::cpSpaceDebugColor,
+    fill_color: sys::cpSpaceDebugColor,
+    data: sys::cpDataPointer,
+) {
+    let drawer = data as *mut D;
+    unsafe {
+        (*drawer).draw_circle(
+            position.into(),
+            angle,
+            radius,
+            outline_color.into(),
+            fill_color.into(),
+        )
+    };
+}
+
+extern "C" fn draw_segment<D: DebugDraw>(
+    a: sys::cpVect,
+    b: sys::cpVect,
+    color: sys::cpSpaceDebugColor,
+    data: sys::cpDataPointer,
+) {
+    let drawer = data as *mut D;
+    unsafe { (*drawer).draw_segment(a.into(), b.into(), color.into()) };
+}
+
+extern "C" fn draw_fat_segment<D: DebugDraw>(
+    a: sys::cpVect,
+    b: sys::cpVect,
+    radius: sys::cpFloat,
+    outline_color: sys::cpSpaceDebugColor,
+    fill_color: sys::cpSpaceDebugColor,
+    data: sys::cpDataPointer,
+) {
+    let drawer = data as *mut D;
+    unsafe {
+        (*drawer).draw_fat_segment(
+            a.into(),
+            b.into(),
+            radius,
+            outline_color.into(),
+            fill_color.into(),
+        )
+    };
+}
+
+extern "C" fn draw_polygon<D: DebugDraw>(
+    count: i32,
+    verts: *const sys::cpVect,
+    radius: sys::cpFloat,
+    outline_color: sys::cpSpaceDebugColor,
+    fill_color: sys::cpSpaceDebugColor,
+    data: sys::cpDataPointer,
+) {
+    let drawer = data as *mut D;
+    unsafe {
+        let verts = core::slice::from_raw_parts(verts as *const Vect, count as usize);
+        (*drawer).draw_polygon(verts, radius, outline_color.into(), fill_color.into())
+    };
+}
+
+extern "C" fn draw_dot<D: DebugDraw>(
+    size: sys::cpFloat,
+    position: sys::cpVect,
+    color: sys::cpSpaceDebugColor,
+    data: sys::cpDataPointer,
+) {
+    let drawer = data as *mut D;
+    unsafe { (*drawer).draw_dot(size, position.into(), color.into()) };
+}
+
+extern "C" fn color_for_shape<D: DebugDraw>(
+    shape: *mut sys::cpShape,
+    data: sys::cpDataPointer,
+) -> sys::cpSpaceDebugColor {
+    let drawer = data as *mut D;
+    let shape = Shape(shape, false);
+    unsafe { (*drawer).color_for_shape(&shape).into() }
+}
+
+/// Build the C drawing options for @c drawer.
+/// The options are left uninitialized from Rust's point of view because the flags field is a
+/// C bitmask that does not have to match one of the bindgen enum's variants.
+pub(crate) fn debug_draw_options<D: DebugDraw>(
+    drawer: &mut D,
+    flags: DebugDrawFlags,
+) -> MaybeUninit<sys::cpSpaceDebugDrawOptions> {
+    let mut options = MaybeUninit::<sys::cpSpaceDebugDrawOptions>::uninit();
+    let ptr = options.as_mut_ptr();
+
+    unsafe {
+        core::ptr::addr_of_mut!((*ptr).drawCircle).write(Some(draw_circle::<D>));
+        core::ptr::addr_of_mut!((*ptr).drawSegment).write(Some(draw_segment::<D>));
+        core::ptr::addr_of_mut!((*ptr).drawFatSegment).write(Some(draw_fat_segment::<D>));
+        core::ptr::addr_of_mut!((*ptr).drawPolygon).write(Some(draw_polygon::<D>));
+        core::ptr::addr_of_mut!((*ptr).drawDot).write(Some(draw_dot::<D>));
+        (core::ptr::addr_of_mut!((*ptr).flags) as *mut u32).write(flags.0);
+        core::ptr::addr_of_mut!((*ptr).shapeOutlineColor)
+            .write(drawer.shape_outline_color().into());
+        core::ptr::addr_of_mut!((*ptr).colorForShape).write(Some(color_for_shape::<D>));
+        core::ptr::addr_of_mut!((*ptr).constraintColor).write(drawer.constraint_color().into());
+        core::ptr::addr_of_mut!((*ptr).collisionPointColor)
+            .write(drawer.collision_point_color().into());
+        core::ptr::addr_of_mut!((*ptr).data).write(drawer as *mut D as *mut c_void);
+    }
+
+    options
+}
@@ -0,0 +1,452 @@
+extern crate chipmunk_sys as sys;
+
+use crate::error::*;
+use crate::shape_filter::*;
+use crate::vect::*;
+use crate::{Body, BodyType, Constraint, PolyError, Shape, ShapeKind};
+use alloc::vec::Vec;
+
+/// Plain data description of a body, for saving and editing physics setups.
+/// The mass and moment are only used by dynamic bodies.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyDef {
+    pub body_type: BodyType,
+    pub mass: Real,
+    pub moment: Real,
+    pub position: Vect,
+    /// Rotation of the body in radians.
+    pub angle: Real,
+    pub velocity: Vect,
+    pub angular_velocity: Real,
+}
+
+impl BodyDef {
+    /// Describe the current state of @c body.
+    pub fn from_body(body: &Body) -> BodyDef {
+        let body_type = body.body_type();
+        // kinematic and static bodies report an infinite mass and moment, which many formats can't store
+        let (mass, moment) = match body_type {
+            BodyType::Dynamic => (body.mass(), body.moment()),
+            _ => (0.0, 0.0),
+        };
+
+        BodyDef {
+            body_type,
+            mass,
+            moment,
+            position: body.position(),
+            angle: body.angle(),
+            velocity: body.velocity(),
+            angular_velocity: body.angular_velocity(),
+        }
+    }
+
+    /// Create a new body from the description.
+    pub fn build(&self) -> Body {
+        let mut body = match self.body_type {
+            BodyType::Dynamic => Body::new(self.mass, self.moment),
+            BodyType::Kinematic => Body::new_kinematic(),
+            BodyType::Static => Body::new_static(),
+        };
+        body.set_position(self.position);
+        body.set_angle(self.angle);
+        body.set_velocity(self.velocity);
+        body.set_angular_velocity(self.angular_velocity);
+        body
+    }
+
+    /// Like build(), but returns an error instead of creating a body from invalid values.
+    pub fn try_build(&self) -> Result<Body, ChipmunkError> {
+        let mut body = match self.body_type {
+            BodyType::Dynamic => Body::try_new(self.mass, self.moment)?,
+            BodyType::Kinematic => Body::new_kinematic(),
+            BodyType::Static => Body::new_static(),
+        };
+        body.try_set_position(self.position)?;
+        body.try_set_angle(self.angle)?;
+        body.try_set_velocity(self.velocity)?;
+        check_finite("angular velocity", self.angular_velocity)?;
+        body.set_angular_velocity(self.angular_velocity);
+        Ok(body)
+    }
+}
+
+impl Default for BodyDef {
+    /// A dynamic body at the origin with no mass, like cpBodyNew(0, 0).
+    fn default() -> BodyDef {
+        BodyDef {
+            body_type: BodyType::Dynamic,
+            mass: 0.0,
+            moment: 0.0,
+            position: Vect::zero(),
+            angle: 0.0,
+            velocity: Vect::zero(),
+            angular_velocity: 0.0,
+        }
+    }
+}
+
+/// The geometry of a ShapeDef, in body local coordinates.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShapeGeometry {
+    Circle {
+        radius: Real,
+        offset: Vect,
+    },
+    Segment {
+        a: Vect,
+        b: Vect,
+        radius: Real,
+    },
+    /// The convex hull of the vertexes is used, like Shape::poly().
+    Poly {
+        verts: Vec<Vect>,
+        radius: Real,
+    },
+}
+
+impl ShapeGeometry {
+    pub fn kind(&self) -> ShapeKind {
+        match self {
+            ShapeGeometry::Circle { .. } => ShapeKind::Circle,
+            ShapeGeometry::Segment { .. } => ShapeKind::Segment,
+            ShapeGeometry::Poly { .. } => ShapeKind::Poly,
+        }
+    }
+}
+
+/// Plain data description of a shape, for saving and editing physics setups.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeDef {
+    pub geometry: ShapeGeometry,
+    pub friction: Real,
+    pub elasticity: Real,
+    pub sensor: bool,
+    pub filter: ShapeFilter,
+    pub collision_type: usize,
+}
+
+impl ShapeDef {
+    /// Describe a shape with the same defaults as Chipmunk's shape constructors.
+    pub fn new(geometry: ShapeGeometry) -> ShapeDef {
+        ShapeDef {
+            geometry,
+            friction: 0.0,
+            elasticity: 0.0,
+            sensor: false,
+            filter: ShapeFilter::ALL,
+            collision_type: 0,
+        }
+    }
+
+    /// Describe the current state of @c shape.
+    pub fn from_shape(shape: &Shape) -> ShapeDef {
+        let geometry = match shape.kind() {
+            ShapeKind::Circle => ShapeGeometry::Circle {
+                radius: shape.circle_radius(),
+                offset: shape.circle_offset(),
+            },
+            ShapeKind::Segment => ShapeGeometry::Segment {
+                a: shape.segment_a(),
+                b: shape.segment_b(),
+                radius: shape.segment_radius(),
+            },
+            ShapeKind::Poly => ShapeGeometry::Poly {
+                verts: shape.poly_verts(),
+                radius: shape.poly_radius(),
+            },
+        };
+
+        ShapeDef {
+            geometry,
+            friction: shape.friction(),
+            elasticity: shape.elasticity(),
+            sensor: shape.is_sensor(),
+            filter: shape.filter(),
+            collision_type: shape.collision_type(),
+        }
+    }
+
+    /// Get the kind of geometry the shape uses.
+    pub fn kind(&self) -> ShapeKind {
+        self.geometry.kind()
+    }
+
+    /// Create a new shape attached to @c body from the description.
+    pub fn build(&self, body: &Body) -> Result<Shape, PolyError> {
+        let shape = match &self.geometry {
+            ShapeGeometry::Circle { radius, offset } => Shape::circle(body, *radius, *offset),
+            ShapeGeometry::Segment { a, b, radius } => Shape::segment(body, *a, *b, *radius),
+            ShapeGeometry::Poly { verts, radius } => Shape::poly(body, verts, *radius)?,
+        };
+        Ok(self.apply(shape))
+    }
+
+    /// Like build(), but also returns an error instead of creating a shape from invalid geometry.
+    pub fn try_build(&self, body: &Body) -> Result<Shape, ChipmunkError> {
+        let shape = match &self.geometry {
+            ShapeGeometry::Circle { radius, offset } => Shape::try_circle(body, *radius, *offset)?,
+            ShapeGeometry::Segment { a, b, radius } => Shape::try_segment(body, *a, *b, *radius)?,
+            ShapeGeometry::Poly { verts, radius } => Shape::poly(body, verts, *radius)?,
+        };
+        Ok(self.apply(shape))
+    }
+
+    fn apply(&self, mut shape: Shape) -> Shape {
+        shape.set_friction(self.friction);
+        shape.set_elasticity(self.elasticity);
+        shape.set_sensor(self.sensor);
+        shape.set_filter(self.filter);
+        shape.set_collision_type(self.collision_type);
+        shape
+    }
+}
+
+/// The kind of a ConstraintDef and its parameters.
+/// Anchors are in the body local coordinates of the body they belong to, and angles are in radians.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointDef {
+    /// Keeps the anchor points at a set distance from one another.
+    PinJoint {
+        anchor_a: Vect,
+        anchor_b: Vect,
+        dist: Real,
+    },
+    /// Like a pin joint, but the distance is only kept between @c min and @c max.
+    SlideJoint {
+        anchor_a: Vect,
+        anchor_b: Vect,
+        min: Real,
+        max: Real,
+    },
+    /// Allows two objects to pivot about a single point.
+    PivotJoint { anchor_a: Vect, anchor_b: Vect },
+    /// Holds the anchor point of body B on the groove of body A.
+    GrooveJoint {
+        groove_a: Vect,
+        groove_b: Vect,
+        anchor_b: Vect,
+    },
+    /// A spring with a damper. Custom spring force functions are not described.
+    DampedSpring {
+        anchor_a: Vect,
+        anchor_b: Vect,
+        rest_length: Real,
+        stiffness: Real,
+        damping: Real,
+    },
+    /// Like a damped spring, but works in an angular fashion. Custom spring torque functions are not described.
+    DampedRotarySpring {
+        rest_angle: Real,
+        stiffness: Real,
+        damping: Real,
+    },
+    /// Constrains the relative rotations of two bodies.
+    RotaryLimitJoint { min: Real, max: Real },
+    /// Works like a socket wrench.
+    RatchetJoint {
+        /// The current ratchet position, which changes as the joint turns.
+        angle: Real,
+        phase: Real,
+        ratchet: Real,
+    },
+    /// Keeps the angular velocity ratio of a pair of bodies constant.
+    GearJoint { phase: Real, ratio: Real },
+    /// Keeps the relative angular velocity of a pair of bodies constant.
+    SimpleMotor { rate: Real },
+}
+
+impl JointDef {
+    /// Describe the kind and parameters of @c constraint.
+    pub fn from_constraint(constraint: &Constraint) -> JointDef {
+        let c = constraint.0;
+        unsafe {
+            if sys::cpConstraintIsPinJoint(c) != 0 {
+                JointDef::PinJoint {
+                    anchor_a: sys::cpPinJointGetAnchorA(c).into(),
+                    anchor_b: sys::cpPinJointGetAnchorB(c).into(),
+                    dist: sys::cpPinJointGetDist(c),
+                }
+            } else if sys::cpConstraintIsSlideJoint(c) != 0 {
+                JointDef::SlideJoint {
+                    anchor_a: sys::cpSlideJointGetAnchorA(c).into(),
+                    anchor_b: sys::cpSlideJointGetAnchorB(c).into(),
+                    min: sys::cpSlideJointGetMin(c),
+                    max: sys::cpSlideJointGetMax(c),
+                }
+            } else if sys::cpConstraintIsPivotJoint(c) != 0 {
+                JointDef::PivotJoint {
+                    anchor_a: sys::cpPivotJointGetAnchorA(c).into(),
+                    anchor_b: sys::cpPivotJointGetAnchorB(c).into(),
+                }
+            } else if sys::cpConstraintIsGrooveJoint(c) != 0 {
+                JointDef::GrooveJoint {
+                    groove_a: sys::cpGrooveJointGetGrooveA(c).into(),
+                    groove_b: sys::cpGrooveJointGetGrooveB(c).into(),
+                    anchor_b: sys::cpGrooveJointGetAnchorB(c).into(),
+                }
+            } else if sys::cpConstraintIsDampedSpring(c) != 0 {
+                JointDef::DampedSpring {
+                    anchor_a: sys::cpDampedSpringGetAnchorA(c).into(),
+                    anchor_b: sys::cpDampedSpringGetAnchorB(c).into(),
+                    rest_length: sys::cpDampedSpringGetRestLength(c),
+                    stiffness: sys::cpDampedSpringGetStiffness(c),
+                    damping: sys::cpDampedSpringGetDamping(c),
+                }
+            } else if sys::cpConstraintIsDampedRotarySpring(c) != 0 {
+                JointDef::DampedRotarySpring {
+                    rest_angle: sys::cpDampedRotarySpringGetRestAngle(c),
+                    stiffness: sys::cpDampedRotarySpringGetStiffness(c),
+                    damping: sys::cpDampedRotarySpringGetDamping(c),
+                }
+            } else if sys::cpConstraintIsRotaryLimitJoint(c) != 0 {
+                JointDef::RotaryLimitJoint {
+                    min: sys::cpRotaryLimitJointGetMin(c),
+                    max: sys::cpRotaryLimitJointGetMax(c),
+                }
+            } else if sys::cpConstraintIsRatchetJoint(c) != 0 {
+                JointDef::RatchetJoint {
+                    angle: sys::cpRatchetJointGetAngle(c),
+                    phase: sys::cpRatchetJointGetPhase(c),
+                    ratchet: sys::cpRatchetJointGetRatchet(c),
+                }
+            } else if sys::cpConstraintIsGearJoint(c) != 0 {
+                JointDef::GearJoint {
+                    phase: sys::cpGearJointGetPhase(c),
+                    ratio: sys::cpGearJointGetRatio(c),
+                }
+            } else if sys::cpConstraintIsSimpleMotor(c) != 0 {
+                JointDef::SimpleMotor {
+                    rate: sys::cpSimpleMotorGetRate(c),
+                }
+            } else {
+                panic!("Constraint is not one of Chipmunk's joint types.")
+            }
+        }
+    }
+
+    unsafe fn new_constraint(
+        &self,
+        a: *mut sys::cpBody,
+        b: *mut sys::cpBody,
+    ) -> *mut sys::cpConstraint {
+        match *self {
+            JointDef::PinJoint {
+                anchor_a,
+                anchor_b,
+                dist,
+            } => {
+                let c = sys::cpPinJointNew(a, b, anchor_a.0, anchor_b.0);
+                sys::cpPinJointSetDist(c, dist);
+                c
+            }
+            JointDef::SlideJoint {
+                anchor_a,
+                anchor_b,
+                min,
+                max,
+            } => sys::cpSlideJointNew(a, b, anchor_a.0, anchor_b.0, min, max),
+            JointDef::PivotJoint { anchor_a, anchor_b } => {
+                sys::cpPivotJointNew2(a, b, anchor_a.0, anchor_b.0)
+            }
+            JointDef::GrooveJoint {
+                groove_a,
+                groove_b,
+                anchor_b,
+            } => sys::cpGrooveJointNew(a, b, groove_a.0, groove_b.0, anchor_b.0),
+            JointDef::DampedSpring {
+                anchor_a,
+                anchor_b,
+                rest_length,
+                stiffness,
+                damping,
+            } => sys::cpDampedSpringNew(
+                a,
+                b,
+                anchor_a.0,
+                anchor_b.0,
+                rest_length,
+                stiffness,
+                damping,
+            ),
+            JointDef::DampedRotarySpring {
+                rest_angle,
+                stiffness,
+                damping,
+            } => sys::cpDampedRotarySpringNew(a, b, rest_angle, stiffness, damping),
+            JointDef::RotaryLimitJoint { min, max } => sys::cpRotaryLimitJointNew(a, b, min, max),
+            JointDef::RatchetJoint {
+                angle,
+                phase,
+                ratchet,
+            } => {
+                let c = sys::cpRatchetJointNew(a, b, phase, ratchet);
+                sys::cpRatchetJointSetAngle(c, angle);
+                c
+            }
+            JointDef::GearJoint { phase, ratio } => sys::cpGearJointNew(a, b, phase, ratio),
+            JointDef::SimpleMotor { rate } => sys::cpSimpleMotorNew(a, b, rate),
+        }
+    }
+}
+
+/// Plain data description of a constraint, for saving and editing physics setups.
+/// The bodies it connects are passed to build() separately.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstraintDef {
+    pub joint: JointDef,
+    pub max_force: Real,
+    pub error_bias: Real,
+    pub max_bias: Real,
+    pub collide_bodies: bool,
+}
+
+impl ConstraintDef {
+    /// Describe a constraint with the same defaults as Chipmunk's constraint constructors.
+    pub fn new(joint: JointDef) -> ConstraintDef {
+        ConstraintDef {
+            joint,
+            max_force: Real::INFINITY,
+            error_bias: default_error_bias(),
+            max_bias: Real::INFINITY,
+            collide_bodies: true,
+        }
+    }
+
+    /// Describe the current state of @c constraint.
+    pub fn from_constraint(constraint: &Constraint) -> ConstraintDef {
+        ConstraintDef {
+            joint: JointDef::from_constraint(constraint),
+            max_force: constraint.max_force(),
+            error_bias: constraint.error_bias(),
+            max_bias: constraint.max_bias(),
+            collide_bodies: constraint.collide_bodies(),
+        }
+    }
+
+    /// Create a new constraint between @c a and @c b from the description.
+    pub fn build(&self, a: &Body, b: &Body) -> Constraint {
+        let mut constraint = Constraint(unsafe { self.joint.new_constraint(a.0, b.0) }, true);
+        constraint.set_max_force(self.max_force);
+        constraint.set_error_bias(self.error_bias);
+        constraint.set_max_bias(self.max_bias);
+        constraint.set_collide_bodies(self.collide_bodies);
+        constraint
+    }
+}
+
+// Chipmunk's default of pow(1.0 - 0.1, 60.0) is read from a new constraint, so it matches in both precisions.
+fn default_error_bias() -> Real {
+    unsafe {
+        // a simple motor doesn't use its bodies until it is added to a space
+        let constraint = sys::cpSimpleMotorNew(core::ptr::null_mut(), core::ptr::null_mut(), 0.0);
+        let error_bias = sys::cpConstraintGetErrorBias(constraint);
+        sys::cpConstraintFree(constraint);
+        error_bias
+    }
+}
@@ -0,0 +1,136 @@
+use crate::shape::PolyError;
+use crate::vect::*;
+use crate::version::*;
+use core::error;
+use core::fmt;
+
+/// A precondition of a Chipmunk function that was not met.
+/// Chipmunk aborts the process when these are violated, so the try_* functions check them first.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChipmunkError {
+    /// The named value was NaN or infinite.
+    NonFinite(&'static str),
+    /// The named value was negative.
+    Negative(&'static str),
+    /// The named value was zero or negative.
+    NonPositive(&'static str),
+    /// The vertexes could not be turned into a polygon.
+    Poly(PolyError),
+    /// Only dynamic bodies have a mass and moment that can be set.
+    NotDynamic,
+    /// The object has already been added to this space.
+    AlreadyInSpace,
+    /// The object has already been added to another space.
+    InOtherSpace,
+    /// The object has not been added to this space.
+    NotInSpace,
+    /// A shape must have a body, which must be added to the space before the shape.
+    BodyNotInSpace,
+    /// Objects cannot be added or removed while the space is locked, such as during a step or query.
+    SpaceLocked,
+    /// The space's own static body cannot be removed from it.
+    StaticBody,
+    /// Bodies can only be put to sleep in a space with a finite sleep time threshold.
+    SleepingDisabled,
+    /// The sleeping group body is not sleeping, or the body is already sleeping in another group.
+    InvalidSleepGroup,
+    /// A snapshot refers to a body, given by its index, that it does not contain.
+    UnknownBody(usize),
+    /// A SpaceState was saved from another space, or the space's objects have changed since.
+    StateMismatch,
+    /// The linked Chipmunk library, whose version is given, does not match the headers.
+    VersionMismatch(&'static str),
+}
+
+impl fmt::Display for ChipmunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChipmunkError::NonFinite(name) => write!(f, "{} must be finite", name),
+            ChipmunkError::Negative(name) => write!(f, "{} must not be negative", name),
+            ChipmunkError::NonPositive(name) => write!(f, "{} must be positive", name),
+            ChipmunkError::Poly(err) => err.fmt(f),
+            ChipmunkError::NotDynamic => write!(f, "body is not dynamic"),
+            ChipmunkError::AlreadyInSpace => {
+                write!(f, "object has already been added to this space")
+            }
+            ChipmunkError::InOtherSpace => {
+                write!(f, "object has already been added to another space")
+            }
+            ChipmunkError::NotInSpace => write!(f, "object has not been added to this space"),
+            ChipmunkError::BodyNotInSpace => {
+                write!(
+                    f,
+                    "shape's body must be added to the space before the shape"
+                )
+            }
+            ChipmunkError::SpaceLocked => write!(f, "space is locked"),
+            ChipmunkError::StaticBody => write!(f, "space's static body cannot be removed"),
+            ChipmunkError::SleepingDisabled => write!(f, "sleeping is not enabled on the space"),
+            ChipmunkError::InvalidSleepGroup => write!(
+                f,
+                "group is not sleeping or the body is already sleeping in another group"
+            ),
+            ChipmunkError::UnknownBody(index) => {
+                write!(f, "snapshot does not contain a body with index {}", index)
+            }
+            ChipmunkError::StateMismatch => write!(
+                f,
+                "state was saved from another space or a different set of objects"
+            ),
+            ChipmunkError::VersionMismatch(linked) => write!(
+                f,
+                "linked Chipmunk {} does not match the Chipmunk {} headers",
+                linked,
+                header_version()
+            ),
+        }
+    }
+}
+
+impl error::Error for ChipmunkError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ChipmunkError::Poly(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<PolyError> for ChipmunkError {
+    fn from(err: PolyError) -> Self {
+        ChipmunkError::Poly(err)
+    }
+}
+
+pub(crate) fn check_finite(name: &'static str, value: Real) -> Result<(), ChipmunkError> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(ChipmunkError::NonFinite(name))
+    }
+}
+
+pub(crate) fn check_finite_vect(name: &'static str, value: Vect) -> Result<(), ChipmunkError> {
+    check_finite(name, value.x())?;
+    check_finite(name, value.y())
+}
+
+/// Infinite values are allowed, as Chipmunk uses them for things like bodies that can't rotate.
+pub(crate) fn check_non_negative(name: &'static str, value: Real) -> Result<(), ChipmunkError> {
+    if value.is_nan() {
+        Err(ChipmunkError::NonFinite(name))
+    } else if value < 0.0 {
+        Err(ChipmunkError::Negative(name))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_positive(name: &'static str, value: Real) -> Result<(), ChipmunkError> {
+    check_finite(name, value)?;
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(ChipmunkError::NonPositive(name))
+    }
+}
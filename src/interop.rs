@@ -0,0 +1,200 @@
+//! Conversions between the value types and those of other math libraries, each behind a feature of the same name.
+//! Without the "std" feature, glam and nalgebra need the "libm" feature as well, which they use for math functions.
+
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+
+use crate::bb::*;
+use crate::transform::*;
+use crate::vect::*;
+
+// Implements the conversions for a library vector or point type with x and y fields.
+// Vectors are converted with an "as" cast when the scalar type doesn't match Real.
+macro_rules! impl_vect_conversions {
+    ($vec:ty, $scalar:ty, $new:expr) => {
+        impl From<Vect> for $vec {
+            fn from(v: Vect) -> Self {
+                $new(v.x() as $scalar, v.y() as $scalar)
+            }
+        }
+
+        impl From<$vec> for Vect {
+            fn from(v: $vec) -> Self {
+                Vect::new(v.x as Real, v.y as Real)
+            }
+        }
+    };
+}
+
+// Implements the conversions between a BB and a (min, max) pair of library points.
+macro_rules! impl_bb_conversions {
+    ($point:ty) => {
+        impl From<BB> for ($point, $point) {
+            fn from(bb: BB) -> Self {
+                (
+                    Vect::new(bb.l(), bb.b()).into(),
+                    Vect::new(bb.r(), bb.t()).into(),
+                )
+            }
+        }
+
+        impl From<($point, $point)> for BB {
+            fn from((min, max): ($point, $point)) -> Self {
+                let (min, max): (Vect, Vect) = (min.into(), max.into());
+                BB::new(min.x(), min.y(), max.x(), max.y())
+            }
+        }
+    };
+}
+
+//MARK: glam
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_conversions {
+    ($vec:ident, $mat:ident, $affine:ident, $scalar:ty) => {
+        impl_vect_conversions!(glam::$vec, $scalar, glam::$vec::new);
+        impl_bb_conversions!(glam::$vec);
+
+        impl From<Transform> for glam::$affine {
+            fn from(t: Transform) -> Self {
+                let t = t.0;
+                glam::$affine::from_mat2_translation(
+                    glam::$mat::from_cols_array(&[
+                        t.a as $scalar,
+                        t.b as $scalar,
+                        t.c as $scalar,
+                        t.d as $scalar,
+                    ]),
+                    glam::$vec::new(t.tx as $scalar, t.ty as $scalar),
+                )
+            }
+        }
+
+        impl From<glam::$affine> for Transform {
+            fn from(t: glam::$affine) -> Self {
+                let (x, y, translation) = (t.matrix2.x_axis, t.matrix2.y_axis, t.translation);
+                Transform::new(
+                    x.x as Real,
+                    x.y as Real,
+                    y.x as Real,
+                    y.y as Real,
+                    translation.x as Real,
+                    translation.y as Real,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_conversions!(DVec2, DMat2, DAffine2, f64);
+#[cfg(feature = "glam")]
+impl_glam_conversions!(Vec2, Mat2, Affine2, f32);
+
+//MARK: nalgebra
+
+#[cfg(feature = "nalgebra")]
+impl_vect_conversions!(nalgebra::Vector2<Real>, Real, nalgebra::Vector2::new);
+#[cfg(feature = "nalgebra")]
+impl_vect_conversions!(nalgebra::Point2<Real>, Real, nalgebra::Point2::new);
+#[cfg(feature = "nalgebra")]
+impl_bb_conversions!(nalgebra::Point2<Real>);
+
+/// The transform as a homogeneous 3x3 matrix.
+#[cfg(feature = "nalgebra")]
+impl From<Transform> for nalgebra::Matrix3<Real> {
+    fn from(t: Transform) -> Self {
+        let t = t.0;
+        nalgebra::Matrix3::new(t.a, t.c, t.tx, t.b, t.d, t.ty, 0.0, 0.0, 1.0)
+    }
+}
+
+/// Only the top two rows of the matrix are used.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix3<Real>> for Transform {
+    fn from(m: nalgebra::Matrix3<Real>) -> Self {
+        Transform::new(
+            m[(0, 0)],
+            m[(1, 0)],
+            m[(0, 1)],
+            m[(1, 1)],
+            m[(0, 2)],
+            m[(1, 2)],
+        )
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Transform> for nalgebra::Affine2<Real> {
+    fn from(t: Transform) -> Self {
+        nalgebra::Affine2::from_matrix_unchecked(t.into())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Affine2<Real>> for Transform {
+    fn from(t: nalgebra::Affine2<Real>) -> Self {
+        t.into_inner().into()
+    }
+}
+
+//MARK: mint
+
+#[cfg(feature = "mint")]
+impl_vect_conversions!(mint::Vector2<Real>, Real, |x, y| mint::Vector2 { x, y });
+#[cfg(feature = "mint")]
+impl_vect_conversions!(mint::Point2<Real>, Real, |x, y| mint::Point2 { x, y });
+#[cfg(feature = "mint")]
+impl_bb_conversions!(mint::Point2<Real>);
+
+/// The columns are the x basis vector, the y basis vector and the translation.
+#[cfg(feature = "mint")]
+impl From<Transform> for mint::ColumnMatrix2x3<Real> {
+    fn from(t: Transform) -> Self {
+        let t = t.0;
+        mint::ColumnMatrix2x3 {
+            x: mint::Vector2 { x: t.a, y: t.b },
+            y: mint::Vector2 { x: t.c, y: t.d },
+            z: mint::Vector2 { x: t.tx, y: t.ty },
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix2x3<Real>> for Transform {
+    fn from(m: mint::ColumnMatrix2x3<Real>) -> Self {
+        Transform::new(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
+    }
+}
+
+//MARK: cgmath
+
+#[cfg(feature = "cgmath")]
+impl_vect_conversions!(cgmath::Vector2<Real>, Real, cgmath::Vector2::new);
+#[cfg(feature = "cgmath")]
+impl_vect_conversions!(cgmath::Point2<Real>, Real, cgmath::Point2::new);
+#[cfg(feature = "cgmath")]
+impl_bb_conversions!(cgmath::Point2<Real>);
+
+/// The transform as a homogeneous 3x3 matrix.
+#[cfg(feature = "cgmath")]
+impl From<Transform> for cgmath::Matrix3<Real> {
+    fn from(t: Transform) -> Self {
+        let t = t.0;
+        cgmath::Matrix3::new(t.a, t.b, 0.0, t.c, t.d, 0.0, t.tx, t.ty, 1.0)
+    }
+}
+
+/// Only the top two rows of the matrix are used.
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Matrix3<Real>> for Transform {
+    fn from(m: cgmath::Matrix3<Real>) -> Self {
+        Transform::new(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
+    }
+}
@@ -1,13 +1,70 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// no_std builds already have core at the crate root
+#[cfg(feature = "std")]
+extern crate core;
+
+mod bb;
 mod body;
+mod callback;
+mod compound;
+mod constraint;
+mod debug_draw;
+mod def;
+mod error;
+#[cfg(any(
+    feature = "cgmath",
+    feature = "glam",
+    feature = "mint",
+    feature = "nalgebra"
+))]
+mod interop;
+mod march;
 mod math;
+#[cfg(feature = "message-handler")]
+mod message;
+mod polyline;
+mod query;
+mod segment_chain;
+#[cfg(feature = "serde")]
+mod serialize;
 mod shape;
+mod shape_filter;
+mod snapshot;
 mod space;
+mod spatial_index;
+#[cfg(feature = "state")]
+mod state;
+mod svg;
+mod transform;
 mod vect;
+mod version;
 
 pub extern crate chipmunk_sys as sys;
 
+pub use bb::*;
 pub use body::*;
+pub use compound::*;
+pub use constraint::*;
+pub use debug_draw::*;
+pub use def::*;
+pub use error::*;
+pub use march::*;
 pub use math::*;
+#[cfg(feature = "message-handler")]
+pub use message::*;
+pub use polyline::*;
+pub use query::*;
+pub use segment_chain::*;
 pub use shape::*;
+pub use shape_filter::*;
+pub use snapshot::*;
 pub use space::*;
+pub use spatial_index::*;
+#[cfg(feature = "state")]
+pub use state::*;
+pub use svg::*;
+pub use transform::*;
 pub use vect::*;
+pub use version::*;
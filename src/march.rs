@@ -0,0 +1,123 @@
+extern crate chipmunk_sys as sys;
+
+use crate::bb::*;
+use crate::callback::*;
+use crate::polyline::*;
+use crate::vect::*;
+use core::convert::TryFrom;
+use core::ffi::{c_ulong, c_void};
+
+type MarchFunc = unsafe extern "C-unwind" fn(
+    sys::cpBB,
+    c_ulong,
+    c_ulong,
+    sys::cpFloat,
+    sys::cpMarchSegmentFunc,
+    *mut c_void,
+    sys::cpMarchSampleFunc,
+    *mut c_void,
+);
+
+fn march<F>(
+    march_func: MarchFunc,
+    bb: BB,
+    x_samples: usize,
+    y_samples: usize,
+    threshold: Real,
+    sample: F,
+) -> PolylineSet
+where
+    F: FnMut(Vect) -> Real,
+{
+    extern "C" fn sample_func<F>(point: sys::cpVect, user_data: *mut c_void) -> sys::cpFloat
+    where
+        F: FnMut(Vect) -> Real,
+    {
+        let callback = user_data as *mut Callback<F>;
+        unsafe { (*callback).call(0.0, |sample| sample(Vect(point))) }
+    }
+
+    extern "C" fn segment_func(v0: sys::cpVect, v1: sys::cpVect, user_data: *mut c_void) {
+        unsafe { sys::cpPolylineSetCollectSegment(v0, v1, user_data as *mut sys::cpPolylineSet) };
+    }
+
+    // cpMarchCells() divides by one less than each count, which would be 0 or wrap around to the maximum
+    assert!(
+        x_samples >= 2 && y_samples >= 2,
+        "Marching needs at least 2 samples along each axis."
+    );
+    let x_samples = c_ulong::try_from(x_samples).expect("Too many samples along the x axis.");
+    let y_samples = c_ulong::try_from(y_samples).expect("Too many samples along the y axis.");
+
+    unsafe {
+        let set = sys::cpPolylineSetNew();
+
+        let mut callback = Callback::new(sample);
+        march_func(
+            bb.0,
+            x_samples,
+            y_samples,
+            threshold,
+            Some(segment_func),
+            set as *mut c_void,
+            Some(sample_func::<F>),
+            &mut callback as *mut _ as *mut c_void,
+        );
+
+        let set = PolylineSet::from_raw(set);
+        callback.finish();
+        set
+    }
+}
+
+/// Trace an anti-aliased contour of an image along a particular threshold.
+/// The given number of samples will be taken and spread across the bounding box area using the sampling function.
+/// Panics if either number of samples is less than 2.
+/// If the sampling function panics, it isn't called again and the panic is resumed once marching is done.
+/// Without the "std" feature, a panic in the sampling function aborts the process instead.
+/// The segments that lie along the density contour for @c threshold are joined into polylines.
+pub fn march_soft<F>(
+    bb: BB,
+    x_samples: usize,
+    y_samples: usize,
+    threshold: Real,
+    sample: F,
+) -> PolylineSet
+where
+    F: FnMut(Vect) -> Real,
+{
+    march(
+        sys::cpMarchSoft,
+        bb,
+        x_samples,
+        y_samples,
+        threshold,
+        sample,
+    )
+}
+
+/// Trace an aliased curve of an image along a particular threshold.
+/// The given number of samples will be taken and spread across the bounding box area using the sampling function.
+/// Panics if either number of samples is less than 2.
+/// If the sampling function panics, it isn't called again and the panic is resumed once marching is done.
+/// Without the "std" feature, a panic in the sampling function aborts the process instead.
+/// The segments that lie along the density contour for @c threshold are joined into polylines.
+pub fn march_hard<F>(
+    bb: BB,
+    x_samples: usize,
+    y_samples: usize,
+    threshold: Real,
+    sample: F,
+) -> PolylineSet
+where
+    F: FnMut(Vect) -> Real,
+{
+    march(
+        sys::cpMarchHard,
+        bb,
+        x_samples,
+        y_samples,
+        threshold,
+        sample,
+    )
+}
@@ -1,36 +1,105 @@
 extern crate chipmunk_sys as sys;
 
+use crate::bb::*;
 use crate::vect::*;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Calculate the moment of inertia for a circle.
 /// @c r1 and @c r2 are the inner and outer diameters. A solid circle has an inner diameter of 0.
 #[inline]
-pub fn moment_for_circle(mass: f64, inner_radius: f64, outer_radius: f64, offset: Vect) -> f64 {
+pub fn moment_for_circle(mass: Real, inner_radius: Real, outer_radius: Real, offset: Vect) -> Real {
     unsafe { sys::cpMomentForCircle(mass, inner_radius, outer_radius, offset.0) }
 }
 
 /// Calculate area of a hollow circle.
 /// @c r1 and @c r2 are the inner and outer diameters. A solid circle has an inner diameter of 0.
 #[inline]
-pub fn area_for_circle(inner_radius: f64, outer_radius: f64) -> f64 {
+pub fn area_for_circle(inner_radius: Real, outer_radius: Real) -> Real {
     unsafe { sys::cpAreaForCircle(inner_radius, outer_radius) }
 }
 
 /// Calculate the moment of inertia for a line segment.
 /// Beveling radius is not supported.
 #[inline]
-pub fn moment_for_segment(mass: f64, a: Vect, b: Vect, radius: f64) -> f64 {
+pub fn moment_for_segment(mass: Real, a: Vect, b: Vect, radius: Real) -> Real {
     unsafe { sys::cpMomentForSegment(mass, a.0, b.0, radius) }
 }
 
 /// Calculate the area of a fattened (capsule shaped) line segment.
 #[inline]
-pub fn area_for_segment(a: Vect, b: Vect, radius: f64) -> f64 {
+pub fn area_for_segment(a: Vect, b: Vect, radius: Real) -> Real {
     unsafe { sys::cpAreaForSegment(a.0, b.0, radius) }
 }
 
 /// Calculate the moment of inertia for a solid box.
 #[inline]
-pub fn moment_for_box(mass: f64, width: f64, height: f64) -> f64 {
+pub fn moment_for_box(mass: Real, width: Real, height: Real) -> Real {
     unsafe { sys::cpMomentForBox(mass, width, height) }
 }
+
+/// Calculate the moment of inertia for a solid box.
+#[inline]
+pub fn moment_for_box2(mass: Real, bb: BB) -> Real {
+    unsafe { sys::cpMomentForBox2(mass, bb.0) }
+}
+
+/// Calculate the moment of inertia for a solid polygon shape assuming it's center of gravity is at it's centroid.
+/// The offset is added to each vertex.
+#[inline]
+pub fn moment_for_poly(mass: Real, verts: &[Vect], offset: Vect, radius: Real) -> Real {
+    unsafe {
+        sys::cpMomentForPoly(
+            mass,
+            verts.len() as i32,
+            verts.as_ptr() as *const sys::cpVect,
+            offset.0,
+            radius,
+        )
+    }
+}
+
+/// Calculate the signed area of a polygon. A counter-clockwise winding gives positive area.
+#[inline]
+pub fn area_for_poly(verts: &[Vect], radius: Real) -> Real {
+    unsafe {
+        sys::cpAreaForPoly(
+            verts.len() as i32,
+            verts.as_ptr() as *const sys::cpVect,
+            radius,
+        )
+    }
+}
+
+/// Calculate the natural centroid of a polygon.
+#[inline]
+pub fn centroid_for_poly(verts: &[Vect]) -> Vect {
+    unsafe { sys::cpCentroidForPoly(verts.len() as i32, verts.as_ptr() as *const sys::cpVect) }
+        .into()
+}
+
+/// Calculate the convex hull of a given set of points.
+/// Returns the hull with a counter-clockwise winding along with the index of the input vertex
+/// that became the first vertex of the hull (i.e. verts[first] == hull[0]).
+/// @c tolerance is the allowed amount to shrink the hull when simplifying it. A tolerance of 0.0 creates an exact hull.
+pub fn convex_hull(verts: &[Vect], tolerance: Real) -> (Vec<Vect>, usize) {
+    if verts.is_empty() {
+        return (Vec::new(), 0);
+    }
+
+    let mut hull = vec![Vect::zero(); verts.len()];
+    let mut first = 0;
+
+    let count = unsafe {
+        sys::cpConvexHull(
+            verts.len() as i32,
+            verts.as_ptr() as *const sys::cpVect,
+            hull.as_mut_ptr() as *mut sys::cpVect,
+            &mut first,
+            tolerance,
+        )
+    };
+
+    hull.truncate(count as usize);
+    (hull, first as usize)
+}
@@ -0,0 +1,227 @@
+extern crate chipmunk_sys as sys;
+#[cfg(feature = "log")]
+extern crate log;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use core::cell::UnsafeCell;
+use core::ffi::CStr;
+use core::ffi::{c_char, c_int};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// A warning or failed precondition reported by one of Chipmunk's assertions.
+#[derive(Clone, Debug)]
+pub struct Message {
+    /// The source text of the condition that failed.
+    pub condition: String,
+    /// The Chipmunk source file containing the assertion.
+    pub file: String,
+    pub line: u32,
+    /// The formatted explanation of what went wrong.
+    pub message: String,
+    /// Chipmunk aborts after reporting an error. Warnings let it continue.
+    pub is_error: bool,
+    /// Hard errors are checked even in release builds of Chipmunk.
+    pub is_hard_error: bool,
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_error {
+            writeln!(f, "Aborting due to Chipmunk error: {}", self.message)?;
+        } else {
+            writeln!(f, "Chipmunk warning: {}", self.message)?;
+        }
+
+        writeln!(f, "\tFailed condition: {}", self.condition)?;
+        write!(f, "\tSource: {}:{}", self.file, self.line)
+    }
+}
+
+/// Where Chipmunk's assertion messages are sent, see set_message_handler().
+pub enum MessageHandler {
+    /// Log warnings with log::warn!() and errors with log::error!().
+    #[cfg(feature = "log")]
+    Log,
+    /// Panic on warnings as well as errors. Without the "bundled" feature, this prints the message and aborts instead.
+    Panic,
+    /// Call a function with each message.
+    /// The function must not call into Chipmunk or set_message_handler(), as the handler is locked while it runs.
+    Callback(Box<dyn Fn(&Message) + Send + Sync>),
+}
+
+#[cfg(feature = "std")]
+static MESSAGE_HANDLER: RwLock<Option<MessageHandler>> = RwLock::new(None);
+
+#[cfg(not(feature = "std"))]
+static MESSAGE_HANDLER: SpinLock<Option<MessageHandler>> = SpinLock::new(None);
+
+/// Stands in for RwLock without std. Messages are rare enough that readers can simply take turns.
+#[cfg(not(feature = "std"))]
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Send + Sync> Sync for SpinLock<T> {}
+
+#[cfg(not(feature = "std"))]
+impl<T> SpinLock<T> {
+    const fn new(value: T) -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn lock(&self) -> SpinLockGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        SpinLockGuard(self)
+    }
+}
+
+/// Unlocks the SpinLock when dropped, including while unwinding from a handler that panicked.
+#[cfg(not(feature = "std"))]
+struct SpinLockGuard<'a, T>(&'a SpinLock<T>);
+
+#[cfg(not(feature = "std"))]
+impl<'a, T> Deref for SpinLockGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.0.value.get() }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<'a, T> DerefMut for SpinLockGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.0.value.get() }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<'a, T> Drop for SpinLockGuard<'a, T> {
+    fn drop(&mut self) {
+        self.0.locked.store(false, Ordering::Release);
+    }
+}
+
+// a handler that panicked is still usable afterwards, so poisoning is ignored
+#[cfg(feature = "std")]
+fn read_handler() -> impl Deref<Target = Option<MessageHandler>> {
+    MESSAGE_HANDLER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "std")]
+fn write_handler() -> impl DerefMut<Target = Option<MessageHandler>> {
+    MESSAGE_HANDLER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(not(feature = "std"))]
+fn read_handler() -> impl Deref<Target = Option<MessageHandler>> {
+    MESSAGE_HANDLER.lock()
+}
+
+#[cfg(not(feature = "std"))]
+fn write_handler() -> impl DerefMut<Target = Option<MessageHandler>> {
+    MESSAGE_HANDLER.lock()
+}
+
+/// Set where Chipmunk's assertion messages are sent. Pass None to restore the default, which logs them like
+/// MessageHandler::Log with the "log" feature, and otherwise drops warnings.
+/// With the "bundled" feature, errors are turned into a panic after calling the handler, instead of aborting the process.
+/// The panic unwinds out of the Chipmunk function that failed, so the objects it was working on may be left in an inconsistent state.
+/// A panic can't unwind out of a callback called by Chipmunk, so errors raised inside one still abort the process.
+/// A Chipmunk library found any other way may not support unwinding, so errors abort the process after calling the handler,
+/// and are printed to stderr first with the "std" feature if the handler didn't report them.
+pub fn set_message_handler(handler: Option<MessageHandler>) {
+    *write_handler() = handler;
+}
+
+/// Called by the cpMessage() replacement in chipmunk-sys with the already formatted message.
+///
+/// # Safety
+/// All of the strings must be valid and nul terminated.
+#[no_mangle]
+pub unsafe extern "C-unwind" fn chipmunk_rs_message(
+    condition: *const c_char,
+    file: *const c_char,
+    line: c_int,
+    is_error: c_int,
+    is_hard_error: c_int,
+    message: *const c_char,
+) {
+    let string = |s: *const c_char| CStr::from_ptr(s).to_string_lossy().into_owned();
+    let message = Message {
+        condition: string(condition),
+        file: string(file),
+        line: line as u32,
+        message: string(message),
+        is_error: is_error != 0,
+        is_hard_error: is_hard_error != 0,
+    };
+
+    // the handler is unlocked before panicking, so that it can still be used afterwards
+    let (reported, fatal) = {
+        let handler = read_handler();
+        match &*handler {
+            #[cfg(feature = "log")]
+            None | Some(MessageHandler::Log) if message.is_error => log::error!("{}", message),
+            #[cfg(feature = "log")]
+            None | Some(MessageHandler::Log) => log::warn!("{}", message),
+            #[cfg(not(feature = "log"))]
+            None => {}
+            Some(MessageHandler::Panic) => {}
+            Some(MessageHandler::Callback(callback)) => callback(&message),
+        }
+
+        let panics = matches!(*handler, Some(MessageHandler::Panic));
+        let reported = !panics && (cfg!(feature = "log") || handler.is_some());
+        (reported, message.is_error || panics)
+    };
+
+    if !fatal {
+        return;
+    }
+
+    // Chipmunk aborts as soon as this returns from an error. The bindings import every Chipmunk function as "C-unwind",
+    // so the panic can unwind back out to the caller, as long as the library was compiled with unwind tables.
+    if sys::CAN_UNWIND {
+        panic!("{}", message);
+    }
+
+    #[cfg(feature = "std")]
+    {
+        if !reported {
+            eprintln!("{}", message);
+        }
+        std::process::abort();
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = reported;
+        extern "C" {
+            fn abort() -> !;
+        }
+        abort();
+    }
+}
@@ -0,0 +1,225 @@
+extern crate chipmunk_sys as sys;
+
+use crate::math::*;
+use crate::vect::*;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Polylines are just arrays of vertexes.
+/// They are looped if the first vertex is equal to the last.
+#[derive(Clone, Debug, Default)]
+pub struct Polyline(pub Vec<Vect>);
+
+impl Polyline {
+    pub fn new(verts: Vec<Vect>) -> Polyline {
+        Polyline(verts)
+    }
+
+    /// Copy a polyline allocated by Chipmunk and free the original.
+    pub(crate) unsafe fn from_raw(line: *mut sys::cpPolyline) -> Polyline {
+        let verts = (*line).verts.as_slice((*line).count as usize);
+        let polyline = Polyline(verts.iter().map(|v| Vect(*v)).collect());
+        sys::cpPolylineFree(line);
+        polyline
+    }
+
+    /// Call @c func with a temporary cpPolyline holding a copy of the vertexes.
+    /// The C functions taking a polyline only read from it, so it is backed by Rust memory.
+    /// They all read the first vertex, so the polyline must not be empty.
+    pub(crate) fn with_raw<R, F>(&self, func: F) -> R
+    where
+        F: FnOnce(*mut sys::cpPolyline) -> R,
+    {
+        debug_assert!(!self.is_empty());
+
+        // The count and capacity header fits in the space of one vertex.
+        let mut buffer = vec![Vect::zero().0; self.0.len() + 1];
+        let line = buffer.as_mut_ptr() as *mut sys::cpPolyline;
+
+        unsafe {
+            (*line).count = self.0.len() as i32;
+            (*line).capacity = self.0.len() as i32;
+            core::ptr::copy_nonoverlapping(
+                self.0.as_ptr() as *const sys::cpVect,
+                (*line).verts.as_mut_ptr(),
+                self.0.len(),
+            );
+        }
+
+        func(line)
+    }
+
+    pub fn verts(&self) -> &[Vect] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if the first vertex is equal to the last.
+    pub fn is_closed(&self) -> bool {
+        self.0.len() > 1 && self.0.first() == self.0.last()
+    }
+
+    /// Returns a copy of a polyline simplified by using the Douglas-Peucker algorithm.
+    /// This works very well on smooth or gently curved shapes, but not well on straight edged or angular shapes.
+    pub fn simplify_curves(&self, tolerance: Real) -> Polyline {
+        if self.len() < 2 {
+            return self.clone();
+        }
+
+        self.with_raw(|line| unsafe {
+            Polyline::from_raw(sys::cpPolylineSimplifyCurves(line, tolerance))
+        })
+    }
+
+    /// Returns a copy of a polyline simplified by discarding "flat" vertexes.
+    /// This works well on straigt edged or angular shapes, not as well on smooth shapes.
+    pub fn simplify_vertexes(&self, tolerance: Real) -> Polyline {
+        if self.len() < 2 {
+            return self.clone();
+        }
+
+        self.with_raw(|line| unsafe {
+            Polyline::from_raw(sys::cpPolylineSimplifyVertexes(line, tolerance))
+        })
+    }
+
+    /// Get the convex hull of a polyline as a looped polyline. The hull of an empty polyline is empty.
+    pub fn to_convex_hull(&self, tolerance: Real) -> Polyline {
+        if self.is_empty() {
+            return Polyline::default();
+        }
+
+        self.with_raw(|line| unsafe {
+            Polyline::from_raw(sys::cpPolylineToConvexHull(line, tolerance))
+        })
+    }
+
+    /// Get an approximate convex decomposition from a polyline.
+    /// Returns a set of convex hulls that match the original shape to within @c tolerance.
+    /// The polyline must be closed and have a counter-clockwise winding.
+    /// NOTE: If the input is a self intersecting polygon, the output might end up overly simplified.
+    pub fn convex_decomposition(&self, tolerance: Real) -> PolylineSet {
+        assert!(
+            self.is_closed(),
+            "Cannot decompose an open polygon. The first and last vertexes must be equal."
+        );
+        assert!(
+            area_for_poly(&self.0, 0.0) > 0.0,
+            "Cannot decompose a polygon with a clockwise winding. (Are you passing a hole?)"
+        );
+
+        self.with_raw(|line| unsafe {
+            PolylineSet::from_raw(sys::cpPolylineConvexDecomposition(line, tolerance))
+        })
+    }
+}
+
+impl From<Vec<Vect>> for Polyline {
+    fn from(verts: Vec<Vect>) -> Self {
+        Polyline(verts)
+    }
+}
+
+impl From<Polyline> for Vec<Vect> {
+    fn from(line: Polyline) -> Self {
+        line.0
+    }
+}
+
+/// Polyline sets are collections of polylines, generally built by march_soft() or march_hard().
+#[derive(Clone, Debug, Default)]
+pub struct PolylineSet(pub Vec<Polyline>);
+
+impl PolylineSet {
+    pub fn new() -> PolylineSet {
+        PolylineSet(Vec::new())
+    }
+
+    /// Copy a polyline set allocated by Chipmunk and free the original along with its polylines.
+    pub(crate) unsafe fn from_raw(set: *mut sys::cpPolylineSet) -> PolylineSet {
+        let lines = core::slice::from_raw_parts((*set).lines, (*set).count as usize);
+        let polylines = lines
+            .iter()
+            .map(|&line| {
+                let verts = (*line).verts.as_slice((*line).count as usize);
+                Polyline(verts.iter().map(|v| Vect(*v)).collect())
+            })
+            .collect();
+        sys::cpPolylineSetFree(set, 1);
+        PolylineSet(polylines)
+    }
+
+    pub fn lines(&self) -> &[Polyline] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add a line segment to a polyline set.
+    /// A segment will either start a new polyline, join two others, or add to or loop an existing polyline.
+    /// This follows the same rules as cpPolylineSetCollectSegment().
+    pub fn collect_segment(&mut self, v0: Vect, v1: Vect) {
+        let before = self.0.iter().position(|line| line.0.last() == Some(&v0));
+        let after = self.0.iter().position(|line| line.0.first() == Some(&v1));
+
+        match (before, after) {
+            (Some(before), Some(after)) if before == after => {
+                // loop by pushing v1 onto before
+                self.0[before].0.push(v1);
+            }
+            (Some(before), Some(after)) => {
+                // join before and after
+                let after_line = self.0.swap_remove(after);
+                let before = if before == self.0.len() {
+                    after
+                } else {
+                    before
+                };
+                self.0[before].0.extend(after_line.0);
+            }
+            (Some(before), None) => {
+                // push v1 onto before
+                self.0[before].0.push(v1);
+            }
+            (None, Some(after)) => {
+                // enqueue v0 onto after
+                self.0[after].0.insert(0, v0);
+            }
+            (None, None) => {
+                // create new line from v0 and v1
+                self.0.push(Polyline(vec![v0, v1]));
+            }
+        }
+    }
+}
+
+impl From<Vec<Polyline>> for PolylineSet {
+    fn from(lines: Vec<Polyline>) -> Self {
+        PolylineSet(lines)
+    }
+}
+
+impl From<Vec<Vec<Vect>>> for PolylineSet {
+    fn from(lines: Vec<Vec<Vect>>) -> Self {
+        PolylineSet(lines.into_iter().map(Polyline).collect())
+    }
+}
+
+impl From<PolylineSet> for Vec<Vec<Vect>> {
+    fn from(set: PolylineSet) -> Self {
+        set.0.into_iter().map(|line| line.0).collect()
+    }
+}
@@ -0,0 +1,158 @@
+extern crate chipmunk_sys as sys;
+
+use crate::vect::*;
+use core::fmt;
+
+/// Point query info struct.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct PointQueryInfo(pub sys::cpPointQueryInfo);
+
+impl PointQueryInfo {
+    /// The closest point on the shape's surface. (in world space coordinates)
+    pub fn point(&self) -> Vect {
+        self.0.point.into()
+    }
+
+    /// The distance to the point. The distance is negative if the point is inside the shape.
+    pub fn distance(&self) -> Real {
+        self.0.distance
+    }
+
+    /// The gradient of the signed distance function.
+    /// The value should be similar to info.p/info.d, but accurate even for very small values of info.d.
+    pub fn gradient(&self) -> Vect {
+        self.0.gradient.into()
+    }
+}
+
+impl From<sys::cpPointQueryInfo> for PointQueryInfo {
+    fn from(info: sys::cpPointQueryInfo) -> Self {
+        PointQueryInfo(info)
+    }
+}
+
+impl fmt::Debug for PointQueryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpPointQueryInfo")
+            .field("point", &self.point())
+            .field("distance", &self.distance())
+            .field("gradient", &self.gradient())
+            .finish()
+    }
+}
+
+/// Segment query info struct.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct SegmentQueryInfo(pub sys::cpSegmentQueryInfo);
+
+impl SegmentQueryInfo {
+    /// The point of impact.
+    pub fn point(&self) -> Vect {
+        self.0.point.into()
+    }
+
+    /// The normal of the surface hit.
+    pub fn normal(&self) -> Vect {
+        self.0.normal.into()
+    }
+
+    /// The normalized distance along the query segment in the range [0, 1].
+    pub fn alpha(&self) -> Real {
+        self.0.alpha
+    }
+}
+
+impl From<sys::cpSegmentQueryInfo> for SegmentQueryInfo {
+    fn from(info: sys::cpSegmentQueryInfo) -> Self {
+        SegmentQueryInfo(info)
+    }
+}
+
+impl fmt::Debug for SegmentQueryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpSegmentQueryInfo")
+            .field("point", &self.point())
+            .field("normal", &self.normal())
+            .field("alpha", &self.alpha())
+            .finish()
+    }
+}
+
+/// A single contact point in a ContactPointSet.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ContactPoint(pub sys::cpContactPointSet__bindgen_ty_1);
+
+impl ContactPoint {
+    /// The position of the contact on the surface of the first shape.
+    pub fn point_a(&self) -> Vect {
+        self.0.pointA.into()
+    }
+
+    /// The position of the contact on the surface of the second shape.
+    pub fn point_b(&self) -> Vect {
+        self.0.pointB.into()
+    }
+
+    /// Penetration distance of the two shapes. Overlapping means it will be negative.
+    pub fn distance(&self) -> Real {
+        self.0.distance
+    }
+}
+
+impl fmt::Debug for ContactPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContactPoint")
+            .field("point_a", &self.point_a())
+            .field("point_b", &self.point_b())
+            .field("distance", &self.distance())
+            .finish()
+    }
+}
+
+/// A struct that wraps up the important collision data for an arbiter.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ContactPointSet(pub sys::cpContactPointSet);
+
+impl ContactPointSet {
+    /// The normal of the collision.
+    pub fn normal(&self) -> Vect {
+        self.0.normal.into()
+    }
+
+    /// The contact points, at most two.
+    pub fn points(&self) -> &[ContactPoint] {
+        let points = &self.0.points[..self.len()];
+        unsafe {
+            &*(points as *const [sys::cpContactPointSet__bindgen_ty_1] as *const [ContactPoint])
+        }
+    }
+
+    /// The number of contact points in the set.
+    pub fn len(&self) -> usize {
+        self.0.count as usize
+    }
+
+    /// Returns true if the shapes are not touching.
+    pub fn is_empty(&self) -> bool {
+        self.0.count == 0
+    }
+}
+
+impl From<sys::cpContactPointSet> for ContactPointSet {
+    fn from(set: sys::cpContactPointSet) -> Self {
+        ContactPointSet(set)
+    }
+}
+
+impl fmt::Debug for ContactPointSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpContactPointSet")
+            .field("normal", &self.normal())
+            .field("points", &self.points())
+            .finish()
+    }
+}
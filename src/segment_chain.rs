@@ -0,0 +1,73 @@
+use crate::vect::*;
+use crate::{Body, Shape};
+use alloc::vec::Vec;
+
+/// A connected run of segment shapes on one body, such as terrain.
+/// Each segment knows about its neighbors so that objects sliding or rolling along the chain
+/// don't catch on the endcaps at the joints.
+pub struct SegmentChain {
+    pub shapes: Vec<Shape>,
+}
+
+impl SegmentChain {
+    /// Create one segment shape for each edge of @c path.
+    /// The path is treated as a closed loop if the first vertex is equal to the last, like a looped Polyline.
+    /// Zero length edges are skipped.
+    pub fn new(body: &Body, path: &[Vect], radius: Real) -> SegmentChain {
+        let mut points = path.to_vec();
+        points.dedup();
+
+        let closed = points.len() > 2 && points.first() == points.last();
+        let count = points.len().saturating_sub(1);
+
+        let shapes = (0..count)
+            .map(|i| {
+                let (a, b) = (points[i], points[i + 1]);
+                let prev = match i {
+                    0 if closed => points[count - 1],
+                    0 => a,
+                    _ => points[i - 1],
+                };
+                let next = match i + 2 {
+                    n if n <= count => points[n],
+                    _ if closed => points[1],
+                    _ => b,
+                };
+
+                let mut shape = Shape::segment(body, a, b, radius);
+                shape.set_segment_neighbors(prev, next);
+                shape
+            })
+            .collect();
+
+        SegmentChain { shapes }
+    }
+
+    /// Returns true if the chain forms a closed loop.
+    pub fn is_closed(&self) -> bool {
+        match (self.shapes.first(), self.shapes.last()) {
+            (Some(first), Some(last)) => {
+                self.shapes.len() > 1 && first.segment_a() == last.segment_b()
+            }
+            _ => false,
+        }
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+}
+
+impl From<SegmentChain> for Vec<Shape> {
+    fn from(chain: SegmentChain) -> Self {
+        chain.shapes
+    }
+}
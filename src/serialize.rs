@@ -0,0 +1,76 @@
+//! Serde support for the value types, behind the "serde" feature.
+//! They are stored as structs with the same fields as their Chipmunk counterparts.
+
+extern crate chipmunk_sys as sys;
+extern crate serde;
+
+use crate::bb::*;
+use crate::shape_filter::*;
+use crate::transform::*;
+use crate::vect::*;
+
+// Implements Serialize and Deserialize for a newtype around a Chipmunk struct, using a serde remote definition of the struct.
+macro_rules! impl_serde {
+    ($type:ident, $remote:ident) => {
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                $remote::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                $remote::deserialize(deserializer).map($type)
+            }
+        }
+    };
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "sys::cpVect", rename = "Vect")]
+struct RemoteVect {
+    x: Real,
+    y: Real,
+}
+
+impl_serde!(Vect, RemoteVect);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "sys::cpBB", rename = "BB")]
+struct RemoteBB {
+    l: Real,
+    b: Real,
+    r: Real,
+    t: Real,
+}
+
+impl_serde!(BB, RemoteBB);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "sys::cpTransform", rename = "Transform")]
+struct RemoteTransform {
+    a: Real,
+    b: Real,
+    c: Real,
+    d: Real,
+    tx: Real,
+    ty: Real,
+}
+
+impl_serde!(Transform, RemoteTransform);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "sys::cpShapeFilter", rename = "ShapeFilter")]
+struct RemoteShapeFilter {
+    group: usize,
+    categories: u32,
+    mask: u32,
+}
+
+impl_serde!(ShapeFilter, RemoteShapeFilter);
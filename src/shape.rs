@@ -1,148 +1,517 @@
 extern crate chipmunk_sys as sys;
 
+use crate::error::*;
+use crate::math::*;
+use crate::query::*;
+use crate::shape_filter::*;
+use crate::space::*;
+use crate::transform::*;
 use crate::vect::*;
-use crate::Body;
-use std::ffi;
+use crate::{Body, BodyRef};
+use alloc::vec::Vec;
+use core::error;
+use core::ffi;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// Reasons a polygon shape could not be created from a set of vertexes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolyError {
+    /// No vertexes were given.
+    Empty,
+    /// A vertex or the radius was NaN or infinite.
+    NonFinite,
+    /// The radius was negative.
+    NegativeRadius,
+    /// The convex hull of the vertexes encloses no area.
+    Degenerate,
+}
+
+impl fmt::Display for PolyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolyError::Empty => write!(f, "polygon has no vertexes"),
+            PolyError::NonFinite => write!(f, "polygon vertexes and radius must be finite"),
+            PolyError::NegativeRadius => write!(f, "polygon radius must not be negative"),
+            PolyError::Degenerate => write!(f, "polygon encloses no area"),
+        }
+    }
+}
+
+impl error::Error for PolyError {}
+
+/// The kind of geometry used by a shape.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShapeKind {
+    Circle,
+    Segment,
+    Poly,
+}
 
 pub struct Shape(pub *mut sys::cpShape, pub bool);
 
 impl Shape {
     pub unsafe fn null() -> Shape {
-        Shape(std::ptr::null_mut(), false)
+        Shape(core::ptr::null_mut(), false)
     }
 
     /// Allocate and initialize a circle shape.
-    pub fn circle(body: &Body, radius: f64, offset: Vect) -> Shape {
+    pub fn circle(body: &Body, radius: Real, offset: Vect) -> Shape {
         Shape(
             unsafe { sys::cpCircleShapeNew(body.0, radius, offset.0) },
             true,
         )
     }
 
+    /// Like circle(), but returns an error if the radius is not positive or a value is not finite.
+    pub fn try_circle(body: &Body, radius: Real, offset: Vect) -> Result<Shape, ChipmunkError> {
+        check_positive("radius", radius)?;
+        check_finite_vect("offset", offset)?;
+        Ok(Shape::circle(body, radius, offset))
+    }
+
     /// Get the radius of a circle shape.
-    pub fn circle_radius(&self) -> f64 {
+    pub fn circle_radius(&self) -> Real {
+        self.assert_kind(ShapeKind::Circle);
         unsafe { sys::cpCircleShapeGetRadius(self.0) }
     }
 
     /// Get the offset of a circle shape.
     pub fn circle_offset(&self) -> Vect {
+        self.assert_kind(ShapeKind::Circle);
         unsafe { sys::cpCircleShapeGetOffset(self.0) }.into()
     }
 
     /// Allocate and initialize a segment shape.
-    pub fn segment(body: &Body, a: Vect, b: Vect, radius: f64) -> Shape {
+    pub fn segment(body: &Body, a: Vect, b: Vect, radius: Real) -> Shape {
         Shape(
             unsafe { sys::cpSegmentShapeNew(body.0, a.0, b.0, radius) },
             true,
         )
     }
 
+    /// Like segment(), but returns an error if the radius is negative or a value is not finite.
+    pub fn try_segment(
+        body: &Body,
+        a: Vect,
+        b: Vect,
+        radius: Real,
+    ) -> Result<Shape, ChipmunkError> {
+        check_finite_vect("a", a)?;
+        check_finite_vect("b", b)?;
+        check_finite("radius", radius)?;
+        check_non_negative("radius", radius)?;
+        Ok(Shape::segment(body, a, b, radius))
+    }
+
+    /// Let Chipmunk know about the geometry of adjacent segments to avoid colliding with endcaps.
+    /// @c prev is the first endpoint of the previous segment and @c next is the second endpoint of the next one.
+    /// Pass the segment's own endpoint for an end without a neighbor.
+    pub fn set_segment_neighbors(&mut self, prev: Vect, next: Vect) {
+        self.assert_kind(ShapeKind::Segment);
+        unsafe { sys::cpSegmentShapeSetNeighbors(self.0, prev.0, next.0) }
+    }
+
+    /// Get the neighbors of a segment shape, as passed to set_segment_neighbors().
+    pub fn segment_neighbors(&self) -> (Vect, Vect) {
+        self.assert_kind(ShapeKind::Segment);
+        let segment = unsafe { &*(self.0 as *const sys::cpSegmentShape) };
+        (
+            Vect(segment.a) + Vect(segment.a_tangent),
+            Vect(segment.b) + Vect(segment.b_tangent),
+        )
+    }
+
     /// Get the first endpoint of a segment shape.
     pub fn segment_a(&self) -> Vect {
+        self.assert_kind(ShapeKind::Segment);
         unsafe { sys::cpSegmentShapeGetA(self.0) }.into()
     }
 
     /// Get the second endpoint of a segment shape.
     pub fn segment_b(&self) -> Vect {
+        self.assert_kind(ShapeKind::Segment);
         unsafe { sys::cpSegmentShapeGetB(self.0) }.into()
     }
 
-    /// Get the first endpoint of a segment shape.
-    pub fn segment_radius(&self) -> f64 {
+    /// Get the radius of a segment shape.
+    pub fn segment_radius(&self) -> Real {
+        self.assert_kind(ShapeKind::Segment);
         unsafe { sys::cpSegmentShapeGetRadius(self.0) }
     }
 
     /// Get the normal of a segment shape.
     pub fn segment_normal(&self) -> Vect {
+        self.assert_kind(ShapeKind::Segment);
         unsafe { sys::cpSegmentShapeGetNormal(self.0) }.into()
     }
 
     /// Allocate and initialize a polygon shape with rounded corners.
-    /// The vertexes must be convex with a counter-clockwise winding.
-    pub fn poly(body: &Body, verts: &[Vect], radius: f64) -> Shape {
+    /// The convex hull of the vertexes is used, so they may be given in any order.
+    /// Returns an error if the vertexes are empty, not finite or enclose no area.
+    pub fn poly(body: &Body, verts: &[Vect], radius: Real) -> Result<Shape, PolyError> {
+        Shape::poly_with_transform(body, verts, Transform::identity(), radius, 0.0)
+    }
+
+    /// Allocate and initialize a polygon shape with rounded corners.
+    /// The vertexes are transformed by @c transform before the convex hull is calculated.
+    /// @c tolerance is the allowed amount to shrink the hull when simplifying it. A tolerance of 0.0 creates an exact hull.
+    pub fn poly_with_transform(
+        body: &Body,
+        verts: &[Vect],
+        transform: Transform,
+        radius: Real,
+        tolerance: Real,
+    ) -> Result<Shape, PolyError> {
+        let hull = poly_hull(verts, transform, radius, tolerance)?;
+        Ok(unsafe { Shape::poly_raw(body, &hull, radius) })
+    }
+
+    /// Allocate and initialize a polygon shape with rounded corners, using the vertexes as is.
+    ///
+    /// # Safety
+    /// The vertexes must be convex with a counter-clockwise winding, and there must be at least one.
+    pub unsafe fn poly_raw(body: &Body, verts: &[Vect], radius: Real) -> Shape {
         Shape(
-            unsafe {
-                sys::cpPolyShapeNewRaw(
-                    body.0,
-                    verts.len() as i32,
-                    verts.as_ptr() as *const _ as *const sys::cpVect,
-                    radius,
-                )
-            },
+            sys::cpPolyShapeNewRaw(
+                body.0,
+                verts.len() as i32,
+                verts.as_ptr() as *const _ as *const sys::cpVect,
+                radius,
+            ),
             true,
         )
     }
 
     /// Allocate and initialize a box shaped polygon shape.
-    pub fn poly_box(body: &Body, width: f64, height: f64, radius: f64) -> Shape {
+    pub fn poly_box(body: &Body, width: Real, height: Real, radius: Real) -> Shape {
         Shape(
             unsafe { sys::cpBoxShapeNew(body.0, width, height, radius) },
             true,
         )
     }
 
-    /// Allocate and initialize a box shaped polygon shape.
+    /// Like poly_box(), but returns an error if the size is not positive or the radius is negative or a value is not finite.
+    pub fn try_poly_box(
+        body: &Body,
+        width: Real,
+        height: Real,
+        radius: Real,
+    ) -> Result<Shape, ChipmunkError> {
+        check_positive("width", width)?;
+        check_positive("height", height)?;
+        check_finite("radius", radius)?;
+        check_non_negative("radius", radius)?;
+        Ok(Shape::poly_box(body, width, height, radius))
+    }
+
+    /// Allocate and initialize a box shaped polygon shape, rotated by @c angle and moved by @c offset.
+    /// Returns an error in the same cases as poly().
     pub fn poly_box_with_transform(
         body: &Body,
-        width: f64,
-        height: f64,
-        radius: f64,
+        width: Real,
+        height: Real,
+        radius: Real,
         offset: Vect,
-        angle: f64,
-    ) -> Shape {
+        angle: Real,
+    ) -> Result<Shape, PolyError> {
         let width = width / 2.0;
         let height = height / 2.0;
         let rotation = Vect::from_angle(angle);
 
         let verts = [
-            (offset + rotation.rotate(Vect::new(-width, -height))).0,
-            (offset + rotation.rotate(Vect::new(width, -height))).0,
-            (offset + rotation.rotate(Vect::new(width, height))).0,
-            (offset + rotation.rotate(Vect::new(-width, height))).0,
+            offset + rotation.rotate(Vect::new(-width, -height)),
+            offset + rotation.rotate(Vect::new(width, -height)),
+            offset + rotation.rotate(Vect::new(width, height)),
+            offset + rotation.rotate(Vect::new(-width, height)),
         ];
 
-        Shape(
-            unsafe { sys::cpPolyShapeNewRaw(body.0, 4, verts.as_ptr(), radius) },
-            true,
-        )
+        Shape::poly(body, &verts, radius)
+    }
+
+    /// Get the number of verts in a polygon shape.
+    pub fn poly_count(&self) -> usize {
+        self.assert_kind(ShapeKind::Poly);
+        unsafe { sys::cpPolyShapeGetCount(self.0) as usize }
+    }
+
+    /// Get the @c ith vertex of a polygon shape.
+    pub fn poly_vert(&self, index: usize) -> Vect {
+        assert!(index < self.poly_count(), "Index out of range.");
+        unsafe { sys::cpPolyShapeGetVert(self.0, index as i32) }.into()
+    }
+
+    /// Get the vertexes of a polygon shape.
+    pub fn poly_verts(&self) -> Vec<Vect> {
+        (0..self.poly_count()).map(|i| self.poly_vert(i)).collect()
+    }
+
+    /// Get the radius of a polygon shape.
+    pub fn poly_radius(&self) -> Real {
+        self.assert_kind(ShapeKind::Poly);
+        unsafe { sys::cpPolyShapeGetRadius(self.0) }
+    }
+
+    /// Set the radius of a circle shape.
+    /// Like the other unsafe_set_* methods this wraps chipmunk_unsafe.h. The geometry changes instantly without
+    /// being simulated, so objects may end up overlapping or tunneling. The shape keeps its contacts and the body's
+    /// mass properties are recalculated if the shape has a mass. The shape's mass stays the same, so its density changes.
+    /// Panics if the space is locked, or if the radius is negative or not finite.
+    pub fn unsafe_set_circle_radius(&mut self, radius: Real) {
+        self.assert_kind(ShapeKind::Circle);
+        assert_radius(radius);
+        self.assert_unlocked();
+        unsafe { sys::cpCircleShapeSetRadius(self.0, radius) };
+        self.geometry_changed();
+    }
+
+    /// Set the offset of a circle shape. See unsafe_set_circle_radius().
+    pub fn unsafe_set_circle_offset(&mut self, offset: Vect) {
+        self.assert_kind(ShapeKind::Circle);
+        self.assert_unlocked();
+        unsafe { sys::cpCircleShapeSetOffset(self.0, offset.0) };
+        self.geometry_changed();
     }
 
-    // /// The cpSpace this body is added to.
-    // CP_EXPORT cpSpace* cpShapeGetSpace(const cpShape *shape);
+    /// Set the endpoints of a segment shape. See unsafe_set_circle_radius().
+    /// This clears the segment's neighbors, which were relative to the old endpoints,
+    /// so call set_segment_neighbors() again afterwards if it has any.
+    pub fn unsafe_set_segment_endpoints(&mut self, a: Vect, b: Vect) {
+        self.assert_kind(ShapeKind::Segment);
+        self.assert_unlocked();
+        unsafe {
+            sys::cpSegmentShapeSetEndpoints(self.0, a.0, b.0);
+            sys::cpSegmentShapeSetNeighbors(self.0, a.0, b.0);
+        }
+        self.geometry_changed();
+    }
 
-    // /// The cpBody this shape is connected to.
-    // CP_EXPORT cpBody* cpShapeGetBody(const cpShape *shape);
-    // /// Set the cpBody this shape is connected to.
-    // /// Can only be used if the shape is not currently added to a space.
-    // CP_EXPORT void cpShapeSetBody(cpShape *shape, cpBody *body);
+    /// Set the radius of a segment shape. See unsafe_set_circle_radius().
+    pub fn unsafe_set_segment_radius(&mut self, radius: Real) {
+        self.assert_kind(ShapeKind::Segment);
+        assert_radius(radius);
+        self.assert_unlocked();
+        unsafe { sys::cpSegmentShapeSetRadius(self.0, radius) };
+        self.geometry_changed();
+    }
+
+    /// Set the vertexes of a polygon shape. See unsafe_set_circle_radius().
+    /// The vertexes are validated and turned into a convex hull in the same way as poly_with_transform().
+    pub fn unsafe_set_poly_verts(
+        &mut self,
+        verts: &[Vect],
+        transform: Transform,
+        tolerance: Real,
+    ) -> Result<(), PolyError> {
+        self.assert_kind(ShapeKind::Poly);
+        let hull = poly_hull(verts, transform, self.poly_radius(), tolerance)?;
+        unsafe { self.unsafe_set_poly_verts_raw(&hull) };
+        Ok(())
+    }
+
+    /// Set the vertexes of a polygon shape, using them as is. See unsafe_set_circle_radius().
+    ///
+    /// # Safety
+    /// The vertexes must be convex with a counter-clockwise winding, and there must be at least one.
+    pub unsafe fn unsafe_set_poly_verts_raw(&mut self, verts: &[Vect]) {
+        self.assert_kind(ShapeKind::Poly);
+        self.assert_unlocked();
+        sys::cpPolyShapeSetVertsRaw(
+            self.0,
+            verts.len() as i32,
+            verts.as_ptr() as *const sys::cpVect as *mut sys::cpVect,
+        );
+        self.geometry_changed();
+    }
+
+    /// Set the radius of a polygon shape. See unsafe_set_circle_radius().
+    pub fn unsafe_set_poly_radius(&mut self, radius: Real) {
+        self.assert_kind(ShapeKind::Poly);
+        assert_radius(radius);
+        self.assert_unlocked();
+        unsafe {
+            sys::cpPolyShapeSetRadius(self.0, radius);
+
+            // unlike the other setters, cpPolyShapeSetRadius() doesn't update the mass properties
+            let verts = self.poly_verts();
+            let count = verts.len() as i32;
+            let verts = verts.as_ptr() as *const sys::cpVect;
+            let cog = sys::cpCentroidForPoly(count, verts);
+            let info = &mut (*self.0).massInfo;
+            info.i = sys::cpMomentForPoly(1.0, count, verts, sys::cpvneg(cog), radius);
+            info.cog = cog;
+            info.area = sys::cpAreaForPoly(count, verts, radius);
+
+            let mass = info.m;
+            if mass > 0.0 && !sys::cpShapeGetBody(self.0).is_null() {
+                // accumulates the body's mass from its shapes again
+                sys::cpShapeSetMass(self.0, mass);
+            }
+        }
+        self.geometry_changed();
+    }
+
+    fn assert_unlocked(&self) {
+        let space = unsafe { sys::cpShapeGetSpace(self.0) };
+        assert!(
+            space.is_null() || unsafe { sys::cpSpaceIsLocked(space) } == 0,
+            "Shape geometry cannot be changed while the space is locked."
+        );
+    }
+
+    /// Update the shape's bounding box and spatial index entry after its geometry changed, and wake its body.
+    /// The setters have already accumulated the body's mass from its shapes when the shape has a mass.
+    fn geometry_changed(&mut self) {
+        unsafe {
+            let space = sys::cpShapeGetSpace(self.0);
+            if space.is_null() {
+                sys::cpShapeCacheBB(self.0);
+            } else {
+                sys::cpSpaceReindexShape(space, self.0);
+            }
+
+            let body = sys::cpShapeGetBody(self.0);
+            if !body.is_null() {
+                sys::cpBodyActivate(body);
+            }
+        }
+    }
+
+    /// Get the kind of geometry this shape uses.
+    pub fn kind(&self) -> ShapeKind {
+        match unsafe { (*(*self.0).klass).type_ } {
+            sys::cpShapeType::CP_CIRCLE_SHAPE => ShapeKind::Circle,
+            sys::cpShapeType::CP_SEGMENT_SHAPE => ShapeKind::Segment,
+            _ => ShapeKind::Poly,
+        }
+    }
+
+    fn assert_kind(&self, kind: ShapeKind) {
+        assert!(
+            self.kind() == kind,
+            "Shape is a {:?} shape, not a {:?} shape.",
+            self.kind(),
+            kind
+        );
+    }
+
+    /// View this shape as a circle, if it is one.
+    pub fn as_circle(&self) -> Option<CircleShape<'_>> {
+        match self.kind() {
+            ShapeKind::Circle => Some(CircleShape(self)),
+            _ => None,
+        }
+    }
+
+    /// View this shape as a segment, if it is one.
+    pub fn as_segment(&self) -> Option<SegmentShape<'_>> {
+        match self.kind() {
+            ShapeKind::Segment => Some(SegmentShape(self)),
+            _ => None,
+        }
+    }
+
+    /// View this shape as a polygon, if it is one.
+    pub fn as_poly(&self) -> Option<PolyShape<'_>> {
+        match self.kind() {
+            ShapeKind::Poly => Some(PolyShape(self)),
+            _ => None,
+        }
+    }
+
+    /// The space this shape is added to, if any.
+    pub fn space(&self) -> Option<SpaceRef<'_>> {
+        unsafe { SpaceRef::from_raw(sys::cpShapeGetSpace(self.0)) }
+    }
+
+    /// The body this shape is connected to.
+    pub fn body(&self) -> Option<BodyRef<'_>> {
+        unsafe { BodyRef::from_raw(sys::cpShapeGetBody(self.0)) }
+    }
+
+    /// Set the body this shape is connected to.
+    /// Can only be used if the shape is not currently added to a space, so remove it first and add it back afterwards.
+    /// The new body's mass properties are updated from the shape when it is added back.
+    /// Returns ChipmunkError::AlreadyInSpace if the shape is added to a space.
+    pub fn set_body(&mut self, body: &Body) -> Result<(), ChipmunkError> {
+        if self.space().is_some() {
+            return Err(ChipmunkError::AlreadyInSpace);
+        }
+
+        unsafe { sys::cpShapeSetBody(self.0, body.0) };
+        Ok(())
+    }
+
+    /// Perform a nearest point query. It finds the closest point on the surface of shape to a specific point.
+    /// Works on shapes that are not added to a space.
+    pub fn point_query(&self, p: Vect) -> PointQueryInfo {
+        self.cache_bb_if_detached();
+        let mut info = MaybeUninit::<sys::cpPointQueryInfo>::uninit();
+        unsafe {
+            sys::cpShapePointQuery(self.0, p.0, info.as_mut_ptr());
+            PointQueryInfo(info.assume_init())
+        }
+    }
+
+    /// Perform a segment query against a shape. Returns None if the segment does not hit the shape.
+    /// Works on shapes that are not added to a space.
+    pub fn segment_query(&self, a: Vect, b: Vect, radius: Real) -> Option<SegmentQueryInfo> {
+        self.cache_bb_if_detached();
+        let mut info = MaybeUninit::<sys::cpSegmentQueryInfo>::uninit();
+        unsafe {
+            if sys::cpShapeSegmentQuery(self.0, a.0, b.0, radius, info.as_mut_ptr()) != 0 {
+                Some(SegmentQueryInfo(info.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return contact information about two shapes. The set is empty if they are not touching.
+    /// Works on shapes that are not added to a space.
+    pub fn collide(&self, other: &Shape) -> ContactPointSet {
+        self.cache_bb_if_detached();
+        other.cache_bb_if_detached();
+        unsafe { sys::cpShapesCollide(self.0, other.0) }.into()
+    }
+
+    /// Shapes in a space are updated when the space steps.
+    /// Other shapes need their world coordinates synced with their body before they can be queried.
+    fn cache_bb_if_detached(&self) {
+        unsafe {
+            if sys::cpShapeGetSpace(self.0).is_null() {
+                sys::cpShapeCacheBB(self.0);
+            }
+        }
+    }
 
     /// Get the mass of the shape if you are having Chipmunk calculate mass properties for you.
-    pub fn mass(&self) -> f64 {
+    pub fn mass(&self) -> Real {
         unsafe { sys::cpShapeGetMass(self.0) }
     }
 
     /// Set the mass of this shape to have Chipmunk calculate mass properties for you.
-    pub fn set_mass(&mut self, mass: f64) {
+    pub fn set_mass(&mut self, mass: Real) {
         unsafe { sys::cpShapeSetMass(self.0, mass) }
     }
 
     /// Get the density of the shape if you are having Chipmunk calculate mass properties for you.
-    pub fn density(&self) -> f64 {
+    pub fn density(&self) -> Real {
         unsafe { sys::cpShapeGetDensity(self.0) }
     }
 
     /// Set the density of this shape to have Chipmunk calculate mass properties for you.
-    pub fn set_density(&mut self, density: f64) {
+    pub fn set_density(&mut self, density: Real) {
         unsafe { sys::cpShapeSetDensity(self.0, density) }
     }
 
     /// Get the calculated moment of inertia for this shape.
-    pub fn moment(&self) -> f64 {
+    pub fn moment(&self) -> Real {
         unsafe { sys::cpShapeGetMoment(self.0) }
     }
 
     /// Get the calculated area of this shape.
-    pub fn area(&self) -> f64 {
+    pub fn area(&self) -> Real {
         unsafe { sys::cpShapeGetArea(self.0) }
     }
 
@@ -165,22 +534,22 @@ impl Shape {
     }
 
     /// Get the elasticity of this shape.
-    pub fn elasticity(&self) -> f64 {
+    pub fn elasticity(&self) -> Real {
         unsafe { sys::cpShapeGetElasticity(self.0) }
     }
 
     /// Set the elasticity of this shape.
-    pub fn set_elasticity(&mut self, elasticity: f64) {
+    pub fn set_elasticity(&mut self, elasticity: Real) {
         unsafe { sys::cpShapeSetElasticity(self.0, elasticity) }
     }
 
     /// Get the friction of this shape.
-    pub fn friction(&self) -> f64 {
+    pub fn friction(&self) -> Real {
         unsafe { sys::cpShapeGetFriction(self.0) }
     }
 
     /// Set the friction of this shape.
-    pub fn set_friction(&mut self, friction: f64) {
+    pub fn set_friction(&mut self, friction: Real) {
         unsafe { sys::cpShapeSetFriction(self.0, friction) }
     }
 
@@ -204,22 +573,73 @@ impl Shape {
         unsafe { sys::cpShapeSetUserData(self.0, user_data) }
     }
 
-    // /// Set the collision type of this shape.
-    // CP_EXPORT cpCollisionType cpShapeGetCollisionType(const cpShape *shape);
-    // /// Get the collision type of this shape.
-    // CP_EXPORT void cpShapeSetCollisionType(cpShape *shape, cpCollisionType collisionType);
+    /// Get the collision type of this shape.
+    pub fn collision_type(&self) -> usize {
+        unsafe { sys::cpShapeGetCollisionType(self.0) }
+    }
+
+    /// Set the collision type of this shape.
+    pub fn set_collision_type(&mut self, collision_type: usize) {
+        unsafe { sys::cpShapeSetCollisionType(self.0, collision_type) }
+    }
+
+    /// Get the collision filtering parameters of this shape.
+    pub fn filter(&self) -> ShapeFilter {
+        unsafe { sys::cpShapeGetFilter(self.0) }.into()
+    }
 
-    // /// Get the collision filtering parameters of this shape.
-    // CP_EXPORT cpShapeFilter cpShapeGetFilter(const cpShape *shape);
-    // /// Set the collision filtering parameters of this shape.
-    // CP_EXPORT void cpShapeSetFilter(cpShape *shape, cpShapeFilter filter);
+    /// Set the collision filtering parameters of this shape.
+    pub fn set_filter(&mut self, filter: ShapeFilter) {
+        unsafe { sys::cpShapeSetFilter(self.0, filter.0) }
+    }
 }
 
 unsafe impl Send for Shape {}
 
+fn assert_radius(radius: Real) {
+    assert!(
+        radius.is_finite() && radius >= 0.0,
+        "Shape radius must be finite and not negative."
+    );
+}
+
+/// Validate a set of polygon vertexes and return their transformed convex hull.
+fn poly_hull(
+    verts: &[Vect],
+    transform: Transform,
+    radius: Real,
+    tolerance: Real,
+) -> Result<Vec<Vect>, PolyError> {
+    if verts.is_empty() {
+        return Err(PolyError::Empty);
+    }
+    if !radius.is_finite()
+        || verts
+            .iter()
+            .any(|v| !v.x().is_finite() || !v.y().is_finite())
+    {
+        return Err(PolyError::NonFinite);
+    }
+    if radius < 0.0 {
+        return Err(PolyError::NegativeRadius);
+    }
+
+    let verts: Vec<Vect> = verts
+        .iter()
+        .map(|&v| transform.transform_point(v))
+        .collect();
+    let (hull, _) = convex_hull(&verts, tolerance);
+    // the radius is left out so a hull of fewer than 3 vertexes doesn't count its rounded edges as area
+    if area_for_poly(&hull, 0.0) <= 0.0 {
+        return Err(PolyError::Degenerate);
+    }
+
+    Ok(hull)
+}
+
 impl Default for Shape {
     fn default() -> Shape {
-        Shape(std::ptr::null_mut(), false)
+        Shape(core::ptr::null_mut(), false)
     }
 }
 
@@ -230,3 +650,71 @@ impl Drop for Shape {
         }
     }
 }
+
+/// A shape known to be a circle.
+#[derive(Copy, Clone)]
+pub struct CircleShape<'a>(pub &'a Shape);
+
+impl<'a> CircleShape<'a> {
+    /// Get the radius of a circle shape.
+    pub fn radius(&self) -> Real {
+        self.0.circle_radius()
+    }
+
+    /// Get the offset of a circle shape.
+    pub fn offset(&self) -> Vect {
+        self.0.circle_offset()
+    }
+}
+
+/// A shape known to be a segment.
+#[derive(Copy, Clone)]
+pub struct SegmentShape<'a>(pub &'a Shape);
+
+impl<'a> SegmentShape<'a> {
+    /// Get the first endpoint of a segment shape.
+    pub fn a(&self) -> Vect {
+        self.0.segment_a()
+    }
+
+    /// Get the second endpoint of a segment shape.
+    pub fn b(&self) -> Vect {
+        self.0.segment_b()
+    }
+
+    /// Get the normal of a segment shape.
+    pub fn normal(&self) -> Vect {
+        self.0.segment_normal()
+    }
+
+    /// Get the radius of a segment shape.
+    pub fn radius(&self) -> Real {
+        self.0.segment_radius()
+    }
+}
+
+/// A shape known to be a polygon.
+#[derive(Copy, Clone)]
+pub struct PolyShape<'a>(pub &'a Shape);
+
+impl<'a> PolyShape<'a> {
+    /// Get the number of verts in a polygon shape.
+    pub fn count(&self) -> usize {
+        self.0.poly_count()
+    }
+
+    /// Get the @c ith vertex of a polygon shape.
+    pub fn vert(&self, index: usize) -> Vect {
+        self.0.poly_vert(index)
+    }
+
+    /// Get the vertexes of a polygon shape.
+    pub fn verts(&self) -> Vec<Vect> {
+        self.0.poly_verts()
+    }
+
+    /// Get the radius of a polygon shape.
+    pub fn radius(&self) -> Real {
+        self.0.poly_radius()
+    }
+}
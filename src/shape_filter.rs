@@ -0,0 +1,102 @@
+extern crate chipmunk_sys as sys;
+
+use core::fmt;
+
+/// Fast collision filtering type that is used to determine if two objects collide before calling collision or query callbacks.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ShapeFilter(pub sys::cpShapeFilter);
+
+impl ShapeFilter {
+    /// Value for group signifying that a shape is in no group.
+    pub const NO_GROUP: usize = 0;
+    /// Value for categories and mask signifying that a shape is in every category.
+    pub const ALL_CATEGORIES: u32 = !0;
+
+    /// Collision filter value for a shape that will collide with anything except CP_SHAPE_FILTER_NONE.
+    pub const ALL: ShapeFilter = ShapeFilter(sys::cpShapeFilter {
+        group: ShapeFilter::NO_GROUP,
+        categories: ShapeFilter::ALL_CATEGORIES,
+        mask: ShapeFilter::ALL_CATEGORIES,
+    });
+
+    /// Collision filter value for a shape that does not collide with anything.
+    pub const NONE: ShapeFilter = ShapeFilter(sys::cpShapeFilter {
+        group: ShapeFilter::NO_GROUP,
+        categories: !ShapeFilter::ALL_CATEGORIES,
+        mask: !ShapeFilter::ALL_CATEGORIES,
+    });
+
+    /// Create a new collision filter.
+    pub fn new(group: usize, categories: u32, mask: u32) -> ShapeFilter {
+        ShapeFilter(sys::cpShapeFilter {
+            group,
+            categories,
+            mask,
+        })
+    }
+
+    /// Two objects with the same non-zero group value do not collide.
+    /// This is generally used to group objects in a composite object together to disable self collisions.
+    pub fn group(&self) -> usize {
+        self.0.group
+    }
+
+    /// A bitmask of user definable categories that this object belongs to.
+    /// The category/mask combinations of both objects in a collision must agree for a collision to occur.
+    pub fn categories(&self) -> u32 {
+        self.0.categories
+    }
+
+    /// A bitmask of user definable category types that this object object collides with.
+    /// The category/mask combinations of both objects in a collision must agree for a collision to occur.
+    pub fn mask(&self) -> u32 {
+        self.0.mask
+    }
+
+    /// Returns true if two filters prevent their shapes from colliding.
+    pub fn reject(&self, other: ShapeFilter) -> bool {
+        let (a, b) = (self.0, other.0);
+        (a.group != 0 && a.group == b.group)
+            || (a.categories & b.mask) == 0
+            || (b.categories & a.mask) == 0
+    }
+}
+
+impl Default for ShapeFilter {
+    fn default() -> ShapeFilter {
+        ShapeFilter::ALL
+    }
+}
+
+impl From<sys::cpShapeFilter> for ShapeFilter {
+    fn from(filter: sys::cpShapeFilter) -> Self {
+        ShapeFilter(filter)
+    }
+}
+
+impl From<ShapeFilter> for sys::cpShapeFilter {
+    fn from(filter: ShapeFilter) -> Self {
+        filter.0
+    }
+}
+
+impl PartialEq for ShapeFilter {
+    fn eq(&self, other: &ShapeFilter) -> bool {
+        self.0.group == other.0.group
+            && self.0.categories == other.0.categories
+            && self.0.mask == other.0.mask
+    }
+}
+
+impl Eq for ShapeFilter {}
+
+impl fmt::Debug for ShapeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpShapeFilter")
+            .field("group", &self.0.group)
+            .field("categories", &self.0.categories)
+            .field("mask", &self.0.mask)
+            .finish()
+    }
+}
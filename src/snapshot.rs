@@ -0,0 +1,323 @@
+extern crate chipmunk_sys as sys;
+
+use crate::body::*;
+use crate::constraint::*;
+use crate::def::*;
+use crate::error::*;
+use crate::shape::*;
+use crate::space::*;
+use crate::vect::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+/// The settings of a space.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpaceSettings {
+    pub iterations: i32,
+    pub gravity: Vect,
+    pub damping: Real,
+    pub idle_speed_threshold: Real,
+    pub sleep_time_threshold: Real,
+    pub collision_slop: Real,
+    pub collision_bias: Real,
+    pub collision_persistance: u32,
+}
+
+/// A body in a SpaceSnapshot.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodySnapshot {
+    pub def: BodyDef,
+    pub center_of_gravity: Vect,
+    pub force: Vect,
+    pub torque: Real,
+    /// Index of the body that identifies the sleeping island the body belongs to, or None if the body is awake.
+    pub sleep_group: Option<usize>,
+    /// How long the body has been idle, used to decide when it falls asleep.
+    pub idle_time: Real,
+}
+
+/// A shape in a SpaceSnapshot.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeSnapshot {
+    /// Index of the body the shape is attached to, or None for the space's static body.
+    pub body: Option<usize>,
+    pub def: ShapeDef,
+    /// The mass of the shape, set directly or from its density, or 0 if the body's mass was set directly.
+    pub mass: Real,
+    pub surface_velocity: Vect,
+    /// The neighbors of a segment shape, see Shape::set_segment_neighbors().
+    pub segment_neighbors: Option<(Vect, Vect)>,
+}
+
+/// A constraint in a SpaceSnapshot.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstraintSnapshot {
+    /// Index of the first body, or None for the space's static body.
+    pub body_a: Option<usize>,
+    /// Index of the second body, or None for the space's static body.
+    pub body_b: Option<usize>,
+    pub def: ConstraintDef,
+}
+
+/// The bodies, shapes and constraints of a space along with their state, see Space::snapshot().
+/// Bodies are referred to by their index in @c bodies.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpaceSnapshot {
+    pub settings: SpaceSettings,
+    pub bodies: Vec<BodySnapshot>,
+    pub shapes: Vec<ShapeSnapshot>,
+    pub constraints: Vec<ConstraintSnapshot>,
+}
+
+/// A space created by Space::from_snapshot(), along with the objects added to it.
+/// The objects are in the same order as in the snapshot, and the space is freed before them.
+pub struct RestoredSpace {
+    pub space: Space,
+    pub bodies: Vec<Body>,
+    pub shapes: Vec<Shape>,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Deref for RestoredSpace {
+    type Target = Space;
+
+    fn deref(&self) -> &Space {
+        &self.space
+    }
+}
+
+impl DerefMut for RestoredSpace {
+    fn deref_mut(&mut self) -> &mut Space {
+        &mut self.space
+    }
+}
+
+impl Space {
+    /// Capture every body, shape and constraint in the space along with its state.
+    /// The space's static body is not captured, shapes and constraints attached to it are reattached to the static body of the restored space.
+    /// Callbacks, user data and custom spring functions are not captured, nor are the cached contacts used to warm start the solver.
+    /// Returns an error if a shape or constraint is attached to a body that is not added to the space.
+    pub fn snapshot(&self) -> Result<SpaceSnapshot, ChipmunkError> {
+        let mut body_ptrs = Vec::new();
+        let mut shape_ptrs = Vec::new();
+        let mut constraint_ptrs = Vec::new();
+        // the pointers are wrapped as unowned objects, so iterating doesn't need to borrow the space mutably
+        let mut space = Space(self.0, false);
+        space.each_body(|body| body_ptrs.push(body.0));
+        space.each_shape(|shape| shape_ptrs.push(shape.0));
+        // each_constraint() skips sleeping constraints, so they are found through their bodies first
+        let static_body = unsafe { sys::cpSpaceGetStaticBody(self.0) };
+        let mut found = BTreeSet::new();
+        for &body in body_ptrs.iter().chain(Some(&static_body)) {
+            unsafe {
+                each_body_constraint(body, |constraint| {
+                    if found.insert(constraint as usize) {
+                        constraint_ptrs.push(constraint);
+                    }
+                })
+            };
+        }
+        // a constraint may be added to the space while neither of its bodies is
+        space.each_constraint(|constraint| {
+            if found.insert(constraint.0 as usize) {
+                constraint_ptrs.push(constraint.0);
+            }
+        });
+
+        let indexes: BTreeMap<usize, usize> = body_ptrs
+            .iter()
+            .enumerate()
+            .map(|(index, &body)| (body as usize, index))
+            .collect();
+        let index_of = |body: *mut sys::cpBody| {
+            if body == static_body {
+                Ok(None)
+            } else {
+                match indexes.get(&(body as usize)) {
+                    Some(&index) => Ok(Some(index)),
+                    None => Err(ChipmunkError::NotInSpace),
+                }
+            }
+        };
+
+        let bodies = body_ptrs
+            .iter()
+            .map(|&ptr| {
+                let body = Body(ptr, false);
+                let sleeping = unsafe { (*ptr).sleeping };
+                Ok(BodySnapshot {
+                    def: BodyDef::from_body(&body),
+                    center_of_gravity: body.center_of_gravity(),
+                    force: body.force(),
+                    torque: body.torque(),
+                    sleep_group: if sleeping.root.is_null() {
+                        None
+                    } else {
+                        index_of(sleeping.root)?
+                    },
+                    idle_time: sleeping.idleTime,
+                })
+            })
+            .collect::<Result<_, ChipmunkError>>()?;
+
+        let shapes = shape_ptrs
+            .iter()
+            .map(|&ptr| {
+                let shape = Shape(ptr, false);
+                let segment_neighbors = match shape.kind() {
+                    ShapeKind::Segment => Some(shape.segment_neighbors()),
+                    _ => None,
+                };
+                Ok(ShapeSnapshot {
+                    body: index_of(unsafe { sys::cpShapeGetBody(ptr) })?,
+                    def: ShapeDef::from_shape(&shape),
+                    mass: shape.mass(),
+                    surface_velocity: shape.surface_velocity(),
+                    segment_neighbors,
+                })
+            })
+            .collect::<Result<_, ChipmunkError>>()?;
+
+        let constraints = constraint_ptrs
+            .iter()
+            .map(|&ptr| {
+                let constraint = Constraint(ptr, false);
+                Ok(ConstraintSnapshot {
+                    body_a: index_of(unsafe { sys::cpConstraintGetBodyA(ptr) })?,
+                    body_b: index_of(unsafe { sys::cpConstraintGetBodyB(ptr) })?,
+                    def: ConstraintDef::from_constraint(&constraint),
+                })
+            })
+            .collect::<Result<_, ChipmunkError>>()?;
+
+        Ok(SpaceSnapshot {
+            settings: SpaceSettings {
+                iterations: self.iterations(),
+                gravity: self.gravity(),
+                damping: self.damping(),
+                idle_speed_threshold: self.idle_speed_threshold(),
+                sleep_time_threshold: self.sleep_time_threshold(),
+                collision_slop: self.collision_slop(),
+                collision_bias: self.collision_bias(),
+                collision_persistance: self.collision_persistance(),
+            },
+            bodies,
+            shapes,
+            constraints,
+        })
+    }
+
+    /// Create a new space with the settings and objects of @c snapshot.
+    /// Returns an error if a body or shape can't be built, if the snapshot refers to a body it doesn't contain,
+    /// or if it has sleeping bodies while the sleep time threshold in its settings is infinite.
+    pub fn from_snapshot(snapshot: &SpaceSnapshot) -> Result<RestoredSpace, ChipmunkError> {
+        let mut restored = RestoredSpace {
+            space: Space::new(),
+            bodies: Vec::with_capacity(snapshot.bodies.len()),
+            shapes: Vec::with_capacity(snapshot.shapes.len()),
+            constraints: Vec::with_capacity(snapshot.constraints.len()),
+        };
+
+        let settings = &snapshot.settings;
+        let space = &mut restored.space;
+        space.set_iterations(settings.iterations);
+        space.set_gravity(settings.gravity);
+        space.set_damping(settings.damping);
+        space.set_idle_speed_threshold(settings.idle_speed_threshold);
+        space.set_sleep_time_threshold(settings.sleep_time_threshold);
+        space.set_collision_slop(settings.collision_slop);
+        space.set_collision_bias(settings.collision_bias);
+        space.set_collision_persistance(settings.collision_persistance);
+
+        for body in &snapshot.bodies {
+            let mut new_body = body.def.try_build()?;
+            set_pose(&mut new_body, body)?;
+            space.add_body(&new_body);
+            restored.bodies.push(new_body);
+        }
+
+        let static_body = space.static_body();
+
+        for shape in &snapshot.shapes {
+            let body = body_at(&restored.bodies, &static_body, shape.body)?;
+            let mut new_shape = shape.def.try_build(body)?;
+            if shape.mass > 0.0 {
+                new_shape.set_mass(shape.mass);
+            }
+            new_shape.set_surface_velocity(shape.surface_velocity);
+            if let Some((prev, next)) = shape.segment_neighbors {
+                new_shape.set_segment_neighbors(prev, next);
+            }
+            space.add_shape(&new_shape);
+            restored.shapes.push(new_shape);
+        }
+
+        for constraint in &snapshot.constraints {
+            let new_constraint = constraint.def.build(
+                body_at(&restored.bodies, &static_body, constraint.body_a)?,
+                body_at(&restored.bodies, &static_body, constraint.body_b)?,
+            );
+            space.add_constraint(new_constraint.0);
+            restored.constraints.push(new_constraint);
+        }
+
+        // adding shapes recalculates the mass and center of gravity of dynamic bodies, so they are set again
+        for (body, new_body) in snapshot.bodies.iter().zip(&mut restored.bodies) {
+            if body.def.body_type == BodyType::Dynamic {
+                new_body.try_set_mass(body.def.mass)?;
+                new_body.try_set_moment(body.def.moment)?;
+                set_pose(new_body, body)?;
+            }
+            new_body.set_force(body.force);
+            new_body.set_torque(body.torque);
+        }
+
+        // each island is put to sleep starting from the body that identifies it
+        for (index, body) in snapshot.bodies.iter().enumerate() {
+            if body.sleep_group == Some(index) {
+                restored.bodies[index].try_sleep_with_group(None)?;
+            }
+        }
+        for (index, body) in snapshot.bodies.iter().enumerate() {
+            match body.sleep_group {
+                Some(group) if group != index => {
+                    let group = body_at(&restored.bodies, &static_body, Some(group))?;
+                    let group = Body(group.0, false);
+                    restored.bodies[index].try_sleep_with_group(Some(&group))?;
+                }
+                _ => {}
+            }
+        }
+
+        for (body, new_body) in snapshot.bodies.iter().zip(&restored.bodies) {
+            unsafe { (*new_body.0).sleeping.idleTime = body.idle_time };
+        }
+
+        Ok(restored)
+    }
+}
+
+fn set_pose(body: &mut Body, snapshot: &BodySnapshot) -> Result<(), ChipmunkError> {
+    check_finite_vect("center of gravity", snapshot.center_of_gravity)?;
+    body.set_center_of_gravity(snapshot.center_of_gravity);
+    // the angle rotates the body about its center of gravity, so it's set before the position
+    body.try_set_angle(snapshot.def.angle)?;
+    body.try_set_position(snapshot.def.position)
+}
+
+fn body_at<'a>(
+    bodies: &'a [Body],
+    static_body: &'a Body,
+    index: Option<usize>,
+) -> Result<&'a Body, ChipmunkError> {
+    match index {
+        Some(index) => bodies.get(index).ok_or(ChipmunkError::UnknownBody(index)),
+        None => Ok(static_body),
+    }
+}
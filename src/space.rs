@@ -1,18 +1,27 @@
 extern crate chipmunk_sys as sys;
 
+use crate::debug_draw::*;
+use crate::error::*;
+use crate::svg::*;
 use crate::vect::*;
-use crate::{Body, Shape};
-use std::ffi;
-use std::os::raw::c_void;
+use crate::version::*;
+use crate::{Body, BodyRef, Constraint, Shape};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ops::Deref;
 
 pub struct Space(pub *mut sys::cpSpace, pub bool);
 
 impl Space {
     pub unsafe fn null() -> Space {
-        Space(std::ptr::null_mut(), false)
+        Space(core::ptr::null_mut(), false)
     }
 
     pub fn new() -> Space {
+        assert_version();
         Space(unsafe { sys::cpSpaceNew() }, true)
     }
 
@@ -40,32 +49,32 @@ impl Space {
     /// A value of 0.9 would mean that each body's velocity will drop 10% per second.
     /// The default value is 1.0, meaning no damping is applied.
     /// @note This damping value is different than those of cpDampedSpring and cpDampedRotarySpring.
-    pub fn damping(&self) -> f64 {
+    pub fn damping(&self) -> Real {
         unsafe { sys::cpSpaceGetDamping(self.0) }
     }
 
-    pub fn set_damping(&mut self, damping: f64) {
+    pub fn set_damping(&mut self, damping: Real) {
         unsafe { sys::cpSpaceSetDamping(self.0, damping) }
     }
 
     /// Speed threshold for a body to be considered idle.
     /// The default value of 0 means to let the space guess a good threshold based on gravity.
-    pub fn idle_speed_threshold(&self) -> f64 {
+    pub fn idle_speed_threshold(&self) -> Real {
         unsafe { sys::cpSpaceGetIdleSpeedThreshold(self.0) }
     }
 
-    pub fn set_idle_speed_threshold(&mut self, idle_speed_threshold: f64) {
+    pub fn set_idle_speed_threshold(&mut self, idle_speed_threshold: Real) {
         unsafe { sys::cpSpaceSetIdleSpeedThreshold(self.0, idle_speed_threshold) }
     }
 
     /// Time a group of bodies must remain idle in order to fall asleep.
     /// Enabling sleeping also implicitly enables the the contact graph.
     /// The default value of INFINITY disables the sleeping algorithm.
-    pub fn sleep_time_threshold(&self) -> f64 {
+    pub fn sleep_time_threshold(&self) -> Real {
         unsafe { sys::cpSpaceGetSleepTimeThreshold(self.0) }
     }
 
-    pub fn set_sleep_time_threshold(&mut self, sleep_time_threshold: f64) {
+    pub fn set_sleep_time_threshold(&mut self, sleep_time_threshold: Real) {
         unsafe { sys::cpSpaceSetSleepTimeThreshold(self.0, sleep_time_threshold) }
     }
 
@@ -73,22 +82,22 @@ impl Space {
     /// Used to reduce oscillating contacts and keep the collision cache warm.
     /// Defaults to 0.1. If you have poor simulation quality,
     /// increase this number as much as possible without allowing visible amounts of overlap.
-    pub fn collision_slop(&self) -> f64 {
+    pub fn collision_slop(&self) -> Real {
         unsafe { sys::cpSpaceGetCollisionSlop(self.0) }
     }
 
-    pub fn set_collision_slop(&mut self, collision_slop: f64) {
+    pub fn set_collision_slop(&mut self, collision_slop: Real) {
         unsafe { sys::cpSpaceSetCollisionSlop(self.0, collision_slop) }
     }
 
     /// Determines how fast overlapping shapes are pushed apart.
     /// Expressed as a fraction of the error remaining after each second.
     /// Defaults to pow(1.0 - 0.1, 60.0) meaning that Chipmunk fixes 10% of overlap each frame at 60Hz.
-    pub fn collision_bias(&self) -> f64 {
+    pub fn collision_bias(&self) -> Real {
         unsafe { sys::cpSpaceGetCollisionSlop(self.0) }
     }
 
-    pub fn set_collision_bias(&mut self, collision_bias: f64) {
+    pub fn set_collision_bias(&mut self, collision_bias: Real) {
         unsafe { sys::cpSpaceSetCollisionBias(self.0, collision_bias) }
     }
 
@@ -121,7 +130,7 @@ impl Space {
 
     /// Returns the current (or most recent) time step used with the given space.
     /// Useful from callbacks if your time step is not a compile-time global.
-    pub fn current_time_step(&self) -> f64 {
+    pub fn current_time_step(&self) -> Real {
         unsafe { sys::cpSpaceGetCurrentTimeStep(self.0) }
     }
 
@@ -173,6 +182,68 @@ impl Space {
         unsafe { sys::cpSpaceRemoveConstraint(self.0, constraint) };
     }
 
+    /// Like add_shape(), but returns an error instead of aborting if the shape can't be added.
+    pub fn try_add_shape(&mut self, shape: &Shape) -> Result<(), ChipmunkError> {
+        self.check_can_add(unsafe { sys::cpShapeGetSpace(shape.0) })?;
+        let body = unsafe { sys::cpShapeGetBody(shape.0) };
+        if body.is_null() || unsafe { sys::cpBodyGetSpace(body) } != self.0 {
+            return Err(ChipmunkError::BodyNotInSpace);
+        }
+
+        self.add_shape(shape);
+        Ok(())
+    }
+
+    /// Like add_body(), but returns an error instead of aborting if the body can't be added.
+    pub fn try_add_body(&mut self, body: &Body) -> Result<(), ChipmunkError> {
+        self.check_can_add(unsafe { sys::cpBodyGetSpace(body.0) })?;
+
+        self.add_body(body);
+        Ok(())
+    }
+
+    /// Like remove_shape(), but returns an error instead of aborting if the shape isn't in the space.
+    pub fn try_remove_shape(&mut self, shape: &Shape) -> Result<(), ChipmunkError> {
+        self.check_can_remove(self.contains_shape(shape))?;
+
+        self.remove_shape(shape.0);
+        Ok(())
+    }
+
+    /// Like remove_body(), but returns an error instead of aborting if the body isn't in the space.
+    /// The space's own static body can't be removed either.
+    pub fn try_remove_body(&mut self, body: &Body) -> Result<(), ChipmunkError> {
+        if body.0 == unsafe { sys::cpSpaceGetStaticBody(self.0) } {
+            return Err(ChipmunkError::StaticBody);
+        }
+        self.check_can_remove(self.contains_body(body))?;
+
+        self.remove_body(body.0);
+        Ok(())
+    }
+
+    fn check_can_add(&self, current_space: *mut sys::cpSpace) -> Result<(), ChipmunkError> {
+        if current_space == self.0 {
+            Err(ChipmunkError::AlreadyInSpace)
+        } else if !current_space.is_null() {
+            Err(ChipmunkError::InOtherSpace)
+        } else if self.is_locked() {
+            Err(ChipmunkError::SpaceLocked)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_can_remove(&self, contained: bool) -> Result<(), ChipmunkError> {
+        if !contained {
+            Err(ChipmunkError::NotInSpace)
+        } else if self.is_locked() {
+            Err(ChipmunkError::SpaceLocked)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Test if a collision shape has been added to the space.
     pub fn contains_shape(&self, shape: &Shape) -> bool {
         unsafe { sys::cpSpaceContainsShape(self.0, shape.0) != 0 }
@@ -183,8 +254,10 @@ impl Space {
         unsafe { sys::cpSpaceContainsBody(self.0, body.0) != 0 }
     }
 
-    // /// Test if a constraint has been added to the space.
-    // CP_EXPORT cpBool cpSpaceContainsConstraint(cpSpace *space, cpConstraint *constraint);
+    /// Test if a constraint has been added to the space.
+    pub fn contains_constraint(&self, constraint: &Constraint) -> bool {
+        unsafe { sys::cpSpaceContainsConstraint(self.0, constraint.0) != 0 }
+    }
 
     // //MARK: Post-Step Callbacks
 
@@ -231,12 +304,12 @@ impl Space {
     /// Call @c func for each body in the space.
     pub fn each_body<F>(&mut self, callback: F)
     where
-        F: Fn(Body),
+        F: FnMut(Body),
     {
         unsafe {
             extern "C" fn each_body<F>(body: *mut sys::cpBody, user_data: *mut c_void)
             where
-                F: Fn(Body),
+                F: FnMut(Body),
             {
                 let callback = user_data as *mut _ as *mut F;
                 unsafe { (*callback)(Body(body, false)) };
@@ -254,12 +327,12 @@ impl Space {
     /// Call @c func for each shape in the space.
     pub fn each_shape<F>(&mut self, callback: F)
     where
-        F: Fn(Shape),
+        F: FnMut(Shape),
     {
         unsafe {
             extern "C" fn each_shape<F>(shape: *mut sys::cpShape, user_data: *mut c_void)
             where
-                F: Fn(Shape),
+                F: FnMut(Shape),
             {
                 let callback = user_data as *mut _ as *mut F;
                 unsafe { (*callback)(Shape(shape, false)) };
@@ -274,10 +347,31 @@ impl Space {
         }
     }
 
-    // /// Space/constraint iterator callback function type.
-    // typedef void (*cpSpaceConstraintIteratorFunc)(cpConstraint *constraint, void *data);
-    // /// Call @c func for each shape in the space.
-    // CP_EXPORT void cpSpaceEachConstraint(cpSpace *space, cpSpaceConstraintIteratorFunc func, void *data);
+    /// Call @c func for each constraint in the space.
+    /// Constraints between sleeping bodies are skipped.
+    pub fn each_constraint<F>(&mut self, callback: F)
+    where
+        F: FnMut(Constraint),
+    {
+        unsafe {
+            extern "C" fn each_constraint<F>(
+                constraint: *mut sys::cpConstraint,
+                user_data: *mut c_void,
+            ) where
+                F: FnMut(Constraint),
+            {
+                let callback = user_data as *mut _ as *mut F;
+                unsafe { (*callback)(Constraint(constraint, false)) };
+            }
+
+            let mut callback_ref = callback;
+            sys::cpSpaceEachConstraint(
+                self.0,
+                Some(each_constraint::<F>),
+                &mut callback_ref as *mut _ as *mut c_void,
+            )
+        }
+    }
 
     // //MARK: Indexing
 
@@ -291,10 +385,72 @@ impl Space {
     // /// Switch the space to use a spatial has as it's spatial index.
     // CP_EXPORT void cpSpaceUseSpatialHash(cpSpace *space, cpFloat dim, int count);
 
+    //MARK: Sleeping
+
+    /// The sleeping islands in the space.
+    /// Each island is a group of touching or jointed bodies that fell asleep together and will wake together.
+    pub fn sleeping_islands(&self) -> Vec<Island<'_>> {
+        unsafe {
+            let components = (*self.0).sleepingComponents;
+            let roots = core::slice::from_raw_parts((*components).arr, (*components).num as usize);
+            roots
+                .iter()
+                .map(|&root| Island::from_root(root as *mut sys::cpBody))
+                .collect()
+        }
+    }
+
+    /// The sleeping island @c body belongs to, or None if the body is awake.
+    /// Chipmunk only tracks islands while they sleep.
+    pub fn island_of(&self, body: &Body) -> Option<Island<'_>> {
+        self.assert_contains_body(body);
+        unsafe {
+            let root = (*body.0).sleeping.root;
+            if root.is_null() || sys::cpBodyIsSleeping(root) == 0 {
+                None
+            } else {
+                Some(Island::from_root(root))
+            }
+        }
+    }
+
+    /// Wake up every body in the sleeping island @c body belongs to.
+    pub fn wake_island(&mut self, body: &Body) {
+        self.assert_contains_body(body);
+        unsafe { sys::cpBodyActivate(body.0) };
+    }
+
+    fn assert_contains_body(&self, body: &Body) {
+        assert!(
+            unsafe { sys::cpBodyGetSpace(body.0) } == self.0,
+            "Body is not added to this space."
+        );
+    }
+
+    //MARK: Debug Draw
+
+    /// Debug draw the current state of the space using the supplied drawing callbacks.
+    pub fn debug_draw<D: DebugDraw>(&mut self, drawer: &mut D, flags: DebugDrawFlags) {
+        let mut options = debug_draw_options(drawer, flags);
+        unsafe { sys::cpSpaceDebugDraw(self.0, options.as_mut_ptr()) };
+    }
+
+    /// Render the current state of the space to an SVG document.
+    pub fn to_svg(&mut self, options: &SvgOptions) -> String {
+        let mut renderer = SvgRenderer::new(*options);
+        self.debug_draw(&mut renderer, options.flags);
+
+        if options.draw_bodies {
+            self.each_body(|body| renderer.draw_body(&body));
+        }
+
+        renderer.finish()
+    }
+
     //MARK: Time Stepping
 
     /// Step the space forward in time by @c dt.
-    pub fn step(&mut self, dt: f64) {
+    pub fn step(&mut self, dt: Real) {
         unsafe { sys::cpSpaceStep(self.0, dt) }
     }
 }
@@ -303,7 +459,7 @@ unsafe impl Send for Space {}
 
 impl Default for Space {
     fn default() -> Space {
-        Space(std::ptr::null_mut(), false)
+        Space(core::ptr::null_mut(), false)
     }
 }
 
@@ -314,3 +470,65 @@ impl Drop for Space {
         }
     }
 }
+
+/// A group of bodies that sleep and wake up together.
+pub struct Island<'a> {
+    bodies: Vec<BodyRef<'a>>,
+}
+
+impl<'a> Island<'a> {
+    unsafe fn from_root(root: *mut sys::cpBody) -> Island<'a> {
+        let mut bodies = Vec::new();
+        let mut body = root;
+        while let Some(body_ref) = BodyRef::from_raw(body) {
+            bodies.push(body_ref);
+            body = (*body).sleeping.next;
+        }
+        Island { bodies }
+    }
+
+    /// The body that identifies the island.
+    pub fn root(&self) -> &Body {
+        &self.bodies[0]
+    }
+
+    pub fn bodies(&self) -> &[BodyRef<'a>] {
+        &self.bodies
+    }
+
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    /// Returns true if @c body belongs to the island.
+    pub fn contains(&self, body: &Body) -> bool {
+        self.bodies.iter().any(|other| (**other).0 == body.0)
+    }
+}
+
+/// A borrowed view of a space owned elsewhere, such as the space a body or shape is added to.
+/// Dropping the view never frees the space.
+pub struct SpaceRef<'a>(Space, PhantomData<&'a Space>);
+
+impl<'a> SpaceRef<'a> {
+    /// Wrap a space pointer without taking ownership of it. Returns None if the pointer is null.
+    pub(crate) unsafe fn from_raw(space: *mut sys::cpSpace) -> Option<SpaceRef<'a>> {
+        if space.is_null() {
+            None
+        } else {
+            Some(SpaceRef(Space(space, false), PhantomData))
+        }
+    }
+}
+
+impl<'a> Deref for SpaceRef<'a> {
+    type Target = Space;
+
+    fn deref(&self) -> &Space {
+        &self.0
+    }
+}
@@ -0,0 +1,348 @@
+extern crate chipmunk_sys as sys;
+
+use crate::bb::*;
+use crate::callback::*;
+use crate::vect::*;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::ffi::c_void;
+
+/// The spatial index algorithm backing a SpatialIndex.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SpatialIndexKind {
+    BBTree,
+    SpaceHash,
+    Sweep1D,
+}
+
+/// Handle to a value that has been inserted into a SpatialIndex.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpatialIndexKey(pub usize);
+
+struct Entry<T> {
+    key: SpatialIndexKey,
+    value: T,
+    bb_func: *const dyn Fn(&T) -> BB,
+}
+
+/// A standalone Chipmunk spatial index holding arbitrary Rust values.
+/// Bounding boxes are obtained on demand from the function given at construction,
+/// so call reindex() or reindex_object() after values move.
+///
+/// If a query callback panics, it isn't called again and the panic is resumed once the query is done.
+/// Without the "std" feature, a panic in a query callback aborts the process instead, as does a panic in the
+/// bounding box function with or without it.
+pub struct SpatialIndex<T> {
+    ptr: *mut sys::cpSpatialIndex,
+    kind: SpatialIndexKind,
+    bb_func: Box<dyn Fn(&T) -> BB>,
+    entries: BTreeMap<usize, Box<Entry<T>>>,
+    next_key: usize,
+}
+
+unsafe extern "C" fn entry_bb<T>(obj: *mut c_void) -> sys::cpBB {
+    let entry = &*(obj as *const Entry<T>);
+    (*entry.bb_func)(&entry.value).0
+}
+
+impl<T> SpatialIndex<T> {
+    fn from_raw<F>(
+        ptr: *mut sys::cpSpatialIndex,
+        kind: SpatialIndexKind,
+        bb_func: F,
+    ) -> SpatialIndex<T>
+    where
+        F: Fn(&T) -> BB + 'static,
+    {
+        SpatialIndex {
+            ptr,
+            kind,
+            bb_func: Box::new(bb_func),
+            entries: BTreeMap::new(),
+            next_key: 0,
+        }
+    }
+
+    /// Allocate and initialize a bounding box tree.
+    pub fn bb_tree<F>(bb_func: F) -> SpatialIndex<T>
+    where
+        F: Fn(&T) -> BB + 'static,
+    {
+        let ptr = unsafe { sys::cpBBTreeNew(Some(entry_bb::<T>), core::ptr::null_mut()) };
+        SpatialIndex::from_raw(ptr, SpatialIndexKind::BBTree, bb_func)
+    }
+
+    /// Allocate and initialize a 1D sort and sweep broadphase.
+    pub fn sweep_1d<F>(bb_func: F) -> SpatialIndex<T>
+    where
+        F: Fn(&T) -> BB + 'static,
+    {
+        let ptr = unsafe { sys::cpSweep1DNew(Some(entry_bb::<T>), core::ptr::null_mut()) };
+        SpatialIndex::from_raw(ptr, SpatialIndexKind::Sweep1D, bb_func)
+    }
+
+    /// Allocate and initialize a spatial hash.
+    /// The cell dimensions should roughly match the average size of your objects
+    /// and the table size should be ~10 larger than the number of objects inserted.
+    pub fn space_hash<F>(cell_dim: Real, cells: i32, bb_func: F) -> SpatialIndex<T>
+    where
+        F: Fn(&T) -> BB + 'static,
+    {
+        let ptr = unsafe {
+            sys::cpSpaceHashNew(cell_dim, cells, Some(entry_bb::<T>), core::ptr::null_mut())
+        };
+        SpatialIndex::from_raw(ptr, SpatialIndexKind::SpaceHash, bb_func)
+    }
+
+    /// Get the algorithm backing this spatial index.
+    pub fn kind(&self) -> SpatialIndexKind {
+        self.kind
+    }
+
+    /// Get the number of objects in the spatial index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the spatial index holds no objects.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if the spatial index contains the given object.
+    pub fn contains(&self, key: SpatialIndexKey) -> bool {
+        match self.entries.get(&key.0) {
+            Some(entry) => unsafe {
+                let obj = &**entry as *const Entry<T> as *mut c_void;
+                sys::cpSpatialIndexContains(self.ptr, obj, key.0) != 0
+            },
+            None => false,
+        }
+    }
+
+    /// Get a reference to an object in the spatial index.
+    pub fn get(&self, key: SpatialIndexKey) -> Option<&T> {
+        self.entries.get(&key.0).map(|entry| &entry.value)
+    }
+
+    /// Get a mutable reference to an object in the spatial index.
+    /// If the change moves the object's bounding box, call reindex_object() afterwards.
+    pub fn get_mut(&mut self, key: SpatialIndexKey) -> Option<&mut T> {
+        self.entries.get_mut(&key.0).map(|entry| &mut entry.value)
+    }
+
+    /// Iterate the objects in the spatial index.
+    pub fn iter(&self) -> impl Iterator<Item = (SpatialIndexKey, &T)> {
+        self.entries.values().map(|entry| (entry.key, &entry.value))
+    }
+
+    /// Add an object to the spatial index.
+    pub fn insert(&mut self, value: T) -> SpatialIndexKey {
+        let key = SpatialIndexKey(self.next_key);
+        self.next_key += 1;
+
+        let entry = Box::new(Entry {
+            key,
+            value,
+            bb_func: &*self.bb_func as *const dyn Fn(&T) -> BB,
+        });
+        let obj = &*entry as *const Entry<T> as *mut c_void;
+        self.entries.insert(key.0, entry);
+
+        unsafe { sys::cpSpatialIndexInsert(self.ptr, obj, key.0) };
+        key
+    }
+
+    /// Remove an object from the spatial index, returning it if it was present.
+    pub fn remove(&mut self, key: SpatialIndexKey) -> Option<T> {
+        let entry = self.entries.remove(&key.0)?;
+        let obj = &*entry as *const Entry<T> as *mut c_void;
+
+        unsafe { sys::cpSpatialIndexRemove(self.ptr, obj, key.0) };
+        Some(entry.value)
+    }
+
+    /// Perform a full reindex of the spatial index.
+    pub fn reindex(&mut self) {
+        unsafe { sys::cpSpatialIndexReindex(self.ptr) };
+    }
+
+    /// Reindex a single object in the spatial index.
+    pub fn reindex_object(&mut self, key: SpatialIndexKey) {
+        if let Some(entry) = self.entries.get(&key.0) {
+            let obj = &**entry as *const Entry<T> as *mut c_void;
+            unsafe { sys::cpSpatialIndexReindexObject(self.ptr, obj, key.0) };
+        }
+    }
+
+    /// Perform a static top down optimization of the tree.
+    /// Has no effect on indexes that are not bounding box trees.
+    pub fn optimize(&mut self) {
+        if self.kind == SpatialIndexKind::BBTree {
+            unsafe { sys::cpBBTreeOptimize(self.ptr) };
+        }
+    }
+
+    /// Change the cell dimensions and table size of the spatial hash to tune it.
+    /// Has no effect on indexes that are not spatial hashes.
+    pub fn resize(&mut self, cell_dim: Real, cells: i32) {
+        if self.kind == SpatialIndexKind::SpaceHash {
+            unsafe { sys::cpSpaceHashResize(self.ptr as *mut sys::cpSpaceHash, cell_dim, cells) };
+        }
+    }
+
+    /// Perform a rectangle query against the spatial index, calling @c func for each potential match.
+    pub fn query<F>(&self, bb: BB, callback: F)
+    where
+        F: FnMut(SpatialIndexKey, &T),
+    {
+        unsafe {
+            extern "C" fn query<T, F>(
+                _obj1: *mut c_void,
+                obj2: *mut c_void,
+                id: sys::cpCollisionID,
+                user_data: *mut c_void,
+            ) -> sys::cpCollisionID
+            where
+                F: FnMut(SpatialIndexKey, &T),
+            {
+                let callback = user_data as *mut Callback<F>;
+                let entry = unsafe { &*(obj2 as *const Entry<T>) };
+                unsafe { (*callback).call((), |func| func(entry.key, &entry.value)) };
+                id
+            }
+
+            let mut callback = Callback::new(callback);
+            sys::cpSpatialIndexQuery(
+                self.ptr,
+                core::ptr::null_mut(),
+                bb.0,
+                Some(query::<T, F>),
+                &mut callback as *mut _ as *mut c_void,
+            );
+            callback.finish();
+        }
+    }
+
+    /// Query the spatial index at a point, calling @c func for each object whose bounding box contains it.
+    pub fn point_query<F>(&self, point: Vect, mut callback: F)
+    where
+        F: FnMut(SpatialIndexKey, &T),
+    {
+        let bb_func = &self.bb_func;
+        self.query(BB::new_for_circle(point, 0.0), |key, value| {
+            if bb_func(value).contains_vect(point) {
+                callback(key, value);
+            }
+        });
+    }
+
+    /// Perform a segment query against the spatial index, calling @c func for each potential match.
+    /// The callback returns the fraction along the segment to keep searching up to,
+    /// return @c t_exit to keep the query unchanged or a smaller value to stop early.
+    pub fn segment_query<F>(&self, a: Vect, b: Vect, t_exit: Real, callback: F)
+    where
+        F: FnMut(SpatialIndexKey, &T) -> Real,
+    {
+        unsafe {
+            extern "C" fn segment_query<T, F>(
+                _obj1: *mut c_void,
+                obj2: *mut c_void,
+                user_data: *mut c_void,
+            ) -> sys::cpFloat
+            where
+                F: FnMut(SpatialIndexKey, &T) -> Real,
+            {
+                let callback = user_data as *mut Callback<F>;
+                let entry = unsafe { &*(obj2 as *const Entry<T>) };
+                // stops the query once the callback panicked
+                unsafe { (*callback).call(0.0, |func| func(entry.key, &entry.value)) }
+            }
+
+            let mut callback = Callback::new(callback);
+            sys::cpSpatialIndexSegmentQuery(
+                self.ptr,
+                core::ptr::null_mut(),
+                a.0,
+                b.0,
+                t_exit,
+                Some(segment_query::<T, F>),
+                &mut callback as *mut _ as *mut c_void,
+            );
+            callback.finish();
+        }
+    }
+
+    /// Simultaneously reindex and find all colliding objects.
+    /// @c func will be called once for each potentially overlapping pair of objects found.
+    pub fn reindex_query<F>(&mut self, callback: F)
+    where
+        F: FnMut((SpatialIndexKey, &T), (SpatialIndexKey, &T)),
+    {
+        unsafe {
+            extern "C" fn reindex_query<T, F>(
+                obj1: *mut c_void,
+                obj2: *mut c_void,
+                id: sys::cpCollisionID,
+                user_data: *mut c_void,
+            ) -> sys::cpCollisionID
+            where
+                F: FnMut((SpatialIndexKey, &T), (SpatialIndexKey, &T)),
+            {
+                let callback = user_data as *mut Callback<F>;
+                let a = unsafe { &*(obj1 as *const Entry<T>) };
+                let b = unsafe { &*(obj2 as *const Entry<T>) };
+                unsafe { (*callback).call((), |func| func((a.key, &a.value), (b.key, &b.value))) };
+                id
+            }
+
+            let mut callback = Callback::new(callback);
+            sys::cpSpatialIndexReindexQuery(
+                self.ptr,
+                Some(reindex_query::<T, F>),
+                &mut callback as *mut _ as *mut c_void,
+            );
+            callback.finish();
+        }
+    }
+
+    /// Collide the objects in this index against the objects in @c static_index,
+    /// calling @c func once for each potentially overlapping pair.
+    pub fn collide_static<U, F>(&self, static_index: &SpatialIndex<U>, callback: F)
+    where
+        F: FnMut((SpatialIndexKey, &T), (SpatialIndexKey, &U)),
+    {
+        unsafe {
+            extern "C" fn collide_static<T, U, F>(
+                obj1: *mut c_void,
+                obj2: *mut c_void,
+                id: sys::cpCollisionID,
+                user_data: *mut c_void,
+            ) -> sys::cpCollisionID
+            where
+                F: FnMut((SpatialIndexKey, &T), (SpatialIndexKey, &U)),
+            {
+                let callback = user_data as *mut Callback<F>;
+                let a = unsafe { &*(obj1 as *const Entry<T>) };
+                let b = unsafe { &*(obj2 as *const Entry<U>) };
+                unsafe { (*callback).call((), |func| func((a.key, &a.value), (b.key, &b.value))) };
+                id
+            }
+
+            let mut callback = Callback::new(callback);
+            sys::cpSpatialIndexCollideStatic(
+                self.ptr,
+                static_index.ptr,
+                Some(collide_static::<T, U, F>),
+                &mut callback as *mut _ as *mut c_void,
+            );
+            callback.finish();
+        }
+    }
+}
+
+impl<T> Drop for SpatialIndex<T> {
+    fn drop(&mut self) {
+        unsafe { sys::cpSpatialIndexFree(self.ptr) };
+    }
+}
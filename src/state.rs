@@ -0,0 +1,715 @@
+extern crate chipmunk_sys as sys;
+
+use crate::constraint::*;
+use crate::error::*;
+use crate::space::*;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::{mem, ptr, slice};
+
+const MAX_CONTACTS: usize = sys::CP_MAX_CONTACTS_PER_ARBITER as usize;
+
+/// The state of a space's objects and cached collisions at one step, for rolling a live space back to it.
+/// It refers to the space's objects directly instead of describing them like SpaceSnapshot does,
+/// so it can only be restored into the space it was saved from while that space holds the same bodies, shapes and constraints.
+/// Saving again reuses the buffers of the previous save.
+///
+/// Saving doesn't change the space. Restoring rebuilds the space's collision detection indexes, whose structure depends
+/// on how the shapes moved before and affects the order contacts are solved in, so that the steps after a restore
+/// only depend on the state. Restoring equal states into spaces built the same way gives the same results when they
+/// are stepped, but these can differ slightly from the steps the space took after the state was saved.
+/// Spaces that need to stay in sync, such as the peers of a networked game, must all restore at the same steps.
+///
+/// This needs the "state" feature, which uses parts of Chipmunk that are not exported by a Windows DLL.
+#[derive(Clone)]
+pub struct SpaceState {
+    space: *mut sys::cpSpace,
+    stamp: sys::cpTimestamp,
+    current_time_step: sys::cpFloat,
+    bodies: Vec<BodyState>,
+    awake_bodies: Vec<*mut sys::cpBody>,
+    sleeping_roots: Vec<*mut sys::cpBody>,
+    shapes: Vec<ShapeState>,
+    planes: Vec<sys::cpSplittingPlane>,
+    constraints: Vec<ConstraintState>,
+    active_constraints: Vec<*mut sys::cpConstraint>,
+    arbiters: Vec<ArbiterState>,
+    active_arbiters: Vec<usize>,
+    // sorted pointers of every body, shape and constraint, to check the space still holds the same objects
+    objects: Vec<usize>,
+    // sorted pairs of arbiter pointers and their index in arbiters, only used while saving
+    arbiter_indexes: Vec<(usize, usize)>,
+}
+
+#[derive(Copy, Clone)]
+struct BodyState {
+    body: *mut sys::cpBody,
+    p: sys::cpVect,
+    v: sys::cpVect,
+    f: sys::cpVect,
+    a: sys::cpFloat,
+    w: sys::cpFloat,
+    t: sys::cpFloat,
+    transform: sys::cpTransform,
+    v_bias: sys::cpVect,
+    w_bias: sys::cpFloat,
+    sleeping: sys::cpBody__bindgen_ty_1,
+    arbiter_list: Option<usize>,
+}
+
+#[derive(Copy, Clone)]
+struct ShapeState {
+    shape: *mut sys::cpShape,
+    bb: sys::cpBB,
+    geometry: CachedGeometry,
+}
+
+// The world space geometry a shape caches along with its bounding box.
+#[derive(Copy, Clone)]
+enum CachedGeometry {
+    Circle {
+        tc: sys::cpVect,
+    },
+    Segment {
+        ta: sys::cpVect,
+        tb: sys::cpVect,
+        tn: sys::cpVect,
+    },
+    // a range of SpaceState::planes holding both the local and world space planes
+    Poly {
+        start: usize,
+        count: usize,
+    },
+}
+
+#[derive(Copy, Clone)]
+struct ConstraintState {
+    constraint: *mut sys::cpConstraint,
+    joint: JointState,
+}
+
+// Saves and restores the whole struct of each joint type, which holds its parameters and the impulses used to warm start the solver.
+macro_rules! joint_states {
+    ($($variant:ident($joint:ident, $is_joint:ident)),*) => {
+        #[derive(Copy, Clone)]
+        enum JointState {
+            $($variant(sys::$joint)),*
+        }
+
+        impl JointState {
+            unsafe fn save(constraint: *mut sys::cpConstraint) -> Option<JointState> {
+                $(
+                    if sys::$is_joint(constraint) != 0 {
+                        return Some(JointState::$variant(*(constraint as *const sys::$joint)));
+                    }
+                )*
+                None
+            }
+
+            unsafe fn restore(&self, constraint: *mut sys::cpConstraint) {
+                match *self {
+                    $(
+                        JointState::$variant(joint) => {
+                            let target = constraint as *mut sys::$joint;
+                            // the base struct links the constraint to its space and bodies, which haven't changed
+                            let base = (*target).constraint;
+                            *target = joint;
+                            (*target).constraint = base;
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+joint_states!(
+    PinJoint(cpPinJoint, cpConstraintIsPinJoint),
+    SlideJoint(cpSlideJoint, cpConstraintIsSlideJoint),
+    PivotJoint(cpPivotJoint, cpConstraintIsPivotJoint),
+    GrooveJoint(cpGrooveJoint, cpConstraintIsGrooveJoint),
+    DampedSpring(cpDampedSpring, cpConstraintIsDampedSpring),
+    DampedRotarySpring(cpDampedRotarySpring, cpConstraintIsDampedRotarySpring),
+    RotaryLimitJoint(cpRotaryLimitJoint, cpConstraintIsRotaryLimitJoint),
+    RatchetJoint(cpRatchetJoint, cpConstraintIsRatchetJoint),
+    GearJoint(cpGearJoint, cpConstraintIsGearJoint),
+    SimpleMotor(cpSimpleMotor, cpConstraintIsSimpleMotor)
+);
+
+#[derive(Copy, Clone)]
+struct ArbiterState {
+    arbiter: *mut sys::cpArbiter,
+    saved: sys::cpArbiter,
+    contacts: [sys::cpContact; MAX_CONTACTS],
+    // indexes of the next and previous arbiters in the lists of body A and body B
+    threads: [Option<usize>; 4],
+    // arbiters of sleeping bodies are kept out of the cache with contacts of their own, see cpSpaceDeactivateBody()
+    sleeping: bool,
+}
+
+impl SpaceState {
+    pub fn new() -> SpaceState {
+        SpaceState {
+            space: ptr::null_mut(),
+            stamp: 0,
+            current_time_step: 0.0,
+            bodies: Vec::new(),
+            awake_bodies: Vec::new(),
+            sleeping_roots: Vec::new(),
+            shapes: Vec::new(),
+            planes: Vec::new(),
+            constraints: Vec::new(),
+            active_constraints: Vec::new(),
+            arbiters: Vec::new(),
+            active_arbiters: Vec::new(),
+            objects: Vec::new(),
+            arbiter_indexes: Vec::new(),
+        }
+    }
+
+    /// Save the state of @c space, replacing any state saved before. The space is left unchanged.
+    pub fn save(&mut self, space: &mut Space) {
+        assert!(
+            !space.is_locked(),
+            "Cannot save the state of a space while it is locked."
+        );
+
+        unsafe {
+            let space = space.0;
+            self.space = space;
+            self.stamp = (*space).stamp;
+            self.current_time_step = (*space).curr_dt;
+            self.save_bodies();
+            self.save_arbiters();
+        }
+    }
+
+    unsafe fn save_bodies(&mut self) {
+        let space = self.space;
+        self.bodies.clear();
+        self.shapes.clear();
+        self.planes.clear();
+        self.constraints.clear();
+        self.objects.clear();
+
+        let SpaceState {
+            bodies,
+            shapes,
+            planes,
+            constraints,
+            objects,
+            ..
+        } = self;
+        each_space_body(space, |body| {
+            bodies.push(BodyState::save(body));
+            objects.push(body as usize);
+
+            each_body_shape(body, |shape| {
+                shapes.push(ShapeState::save(shape, planes));
+                objects.push(shape as usize);
+            });
+        });
+        each_space_constraint(space, |constraint| {
+            if let Some(joint) = JointState::save(constraint) {
+                constraints.push(ConstraintState { constraint, joint });
+            }
+            objects.push(constraint as usize);
+        });
+        self.objects.sort_unstable();
+
+        copy_array((*space).dynamicBodies, &mut self.awake_bodies);
+        copy_array((*space).sleepingComponents, &mut self.sleeping_roots);
+        copy_array((*space).constraints, &mut self.active_constraints);
+    }
+
+    unsafe fn save_arbiters(&mut self) {
+        extern "C" fn save_cached_arbiter(arbiter: *mut c_void, arbiters: *mut c_void) {
+            let arbiters = arbiters as *mut Vec<ArbiterState>;
+            unsafe { (*arbiters).push(ArbiterState::save(arbiter as *mut sys::cpArbiter, false)) };
+        }
+
+        let space = self.space;
+        self.arbiters.clear();
+        sys::cpHashSetEach(
+            (*space).cachedArbiters,
+            Some(save_cached_arbiter),
+            &mut self.arbiters as *mut _ as *mut c_void,
+        );
+        let arbiters = &mut self.arbiters;
+        each_sleeping_arbiter(space, |arbiter| {
+            arbiters.push(ArbiterState::save(arbiter, true));
+        });
+
+        self.arbiter_indexes.clear();
+        self.arbiter_indexes.extend(
+            self.arbiters
+                .iter()
+                .enumerate()
+                .map(|(index, arbiter)| (arbiter.arbiter as usize, index)),
+        );
+        self.arbiter_indexes.sort_unstable();
+
+        let indexes = &self.arbiter_indexes;
+        for arbiter in &mut self.arbiters {
+            let saved = &arbiter.saved;
+            arbiter.threads = [
+                arbiter_index(indexes, saved.thread_a.next),
+                arbiter_index(indexes, saved.thread_a.prev),
+                arbiter_index(indexes, saved.thread_b.next),
+                arbiter_index(indexes, saved.thread_b.prev),
+            ];
+        }
+        for body in &mut self.bodies {
+            body.arbiter_list = arbiter_index(indexes, (*body.body).arbiterList);
+        }
+
+        self.active_arbiters.clear();
+        for &arbiter in array::<sys::cpArbiter>((*space).arbiters) {
+            if let Some(index) = arbiter_index(indexes, arbiter) {
+                self.active_arbiters.push(index);
+            }
+        }
+    }
+
+    /// Restore the state into @c space, which must be the space it was saved from.
+    /// Returns an error if the space is locked, or if it isn't holding the same bodies, shapes and constraints as when the state was saved,
+    /// or if the number of vertexes of a polygon shape was changed since.
+    pub fn restore(&self, space: &mut Space) -> Result<(), ChipmunkError> {
+        if space.is_locked() {
+            return Err(ChipmunkError::SpaceLocked);
+        }
+        if !self.matches(space.0) {
+            return Err(ChipmunkError::StateMismatch);
+        }
+
+        unsafe {
+            let space = space.0;
+            release_arbiters(space);
+
+            for body in &self.bodies {
+                body.restore();
+            }
+            set_array((*space).dynamicBodies, &self.awake_bodies);
+            set_array((*space).sleepingComponents, &self.sleeping_roots);
+
+            for shape in &self.shapes {
+                shape.restore(&self.planes);
+            }
+
+            for constraint in &self.constraints {
+                constraint.joint.restore(constraint.constraint);
+            }
+            set_array((*space).constraints, &self.active_constraints);
+
+            (*space).stamp = self.stamp;
+            (*space).curr_dt = self.current_time_step;
+            rebuild_spatial_indexes(space);
+            self.restore_arbiters();
+        }
+
+        Ok(())
+    }
+
+    // Returns true if the space holds exactly the same bodies, shapes and constraints as when the state was saved,
+    // and its polygon shapes have as many vertexes as they had.
+    fn matches(&self, space: *mut sys::cpSpace) -> bool {
+        if space != self.space {
+            return false;
+        }
+
+        let mut count = 0;
+        let mut found = true;
+        let mut check = |object: usize| {
+            count += 1;
+            found &= self.objects.binary_search(&object).is_ok();
+        };
+        unsafe {
+            each_space_body(space, |body| {
+                check(body as usize);
+                each_body_shape(body, |shape| check(shape as usize));
+            });
+            each_space_constraint(space, |constraint| check(constraint as usize));
+        }
+
+        // the shapes are known to still be in the space once all of the objects were found
+        found
+            && count == self.objects.len()
+            && self.shapes.iter().all(|shape| unsafe { shape.matches() })
+    }
+
+    unsafe fn restore_arbiters(&self) {
+        let space = self.space;
+        sys::cpSpacePushFreshContactBuffer(space);
+
+        let arbiters: Vec<*mut sys::cpArbiter> = self
+            .arbiters
+            .iter()
+            .map(|arbiter| arbiter.restore(space))
+            .collect();
+        let arbiter_at =
+            |index: Option<usize>| index.map_or(ptr::null_mut(), |index| arbiters[index]);
+
+        for (state, &arbiter) in self.arbiters.iter().zip(&arbiters) {
+            (*arbiter).thread_a.next = arbiter_at(state.threads[0]);
+            (*arbiter).thread_a.prev = arbiter_at(state.threads[1]);
+            (*arbiter).thread_b.next = arbiter_at(state.threads[2]);
+            (*arbiter).thread_b.prev = arbiter_at(state.threads[3]);
+        }
+        for body in &self.bodies {
+            (*body.body).arbiterList = arbiter_at(body.arbiter_list);
+        }
+
+        // the cache's buckets are built by pushing to the front, so they are filled in reverse to keep their order
+        for (state, &arbiter) in self.arbiters.iter().zip(&arbiters).rev() {
+            if !state.sleeping {
+                let shape_pair = [(*arbiter).a, (*arbiter).b];
+                sys::cpHashSetInsert(
+                    (*space).cachedArbiters,
+                    sys::CP_HASH_PAIR(shape_pair[0] as usize, shape_pair[1] as usize),
+                    shape_pair.as_ptr() as *const c_void,
+                    None,
+                    arbiter as *mut c_void,
+                );
+            }
+        }
+
+        let active = (*space).arbiters;
+        (*active).num = 0;
+        for &index in &self.active_arbiters {
+            sys::cpArrayPush(active, arbiters[index] as *mut c_void);
+        }
+    }
+}
+
+impl Default for SpaceState {
+    fn default() -> SpaceState {
+        SpaceState::new()
+    }
+}
+
+unsafe impl Send for SpaceState {}
+
+impl BodyState {
+    unsafe fn save(body: *mut sys::cpBody) -> BodyState {
+        let b = &*body;
+        BodyState {
+            body,
+            p: b.p,
+            v: b.v,
+            f: b.f,
+            a: b.a,
+            w: b.w,
+            t: b.t,
+            transform: b.transform,
+            v_bias: b.v_bias,
+            w_bias: b.w_bias,
+            sleeping: b.sleeping,
+            arbiter_list: None,
+        }
+    }
+
+    unsafe fn restore(&self) {
+        let b = &mut *self.body;
+        b.p = self.p;
+        b.v = self.v;
+        b.f = self.f;
+        b.a = self.a;
+        b.w = self.w;
+        b.t = self.t;
+        b.transform = self.transform;
+        b.v_bias = self.v_bias;
+        b.w_bias = self.w_bias;
+        b.sleeping = self.sleeping;
+    }
+}
+
+impl ShapeState {
+    unsafe fn save(
+        shape: *mut sys::cpShape,
+        planes: &mut Vec<sys::cpSplittingPlane>,
+    ) -> ShapeState {
+        let geometry = match (*(*shape).klass).type_ {
+            sys::cpShapeType::CP_CIRCLE_SHAPE => CachedGeometry::Circle {
+                tc: (*(shape as *mut sys::cpCircleShape)).tc,
+            },
+            sys::cpShapeType::CP_SEGMENT_SHAPE => {
+                let segment = &*(shape as *mut sys::cpSegmentShape);
+                CachedGeometry::Segment {
+                    ta: segment.ta,
+                    tb: segment.tb,
+                    tn: segment.tn,
+                }
+            }
+            _ => {
+                let poly = &*(shape as *mut sys::cpPolyShape);
+                let count = poly.count as usize;
+                let start = planes.len();
+                planes.extend_from_slice(slice::from_raw_parts(poly.planes, 2 * count));
+                CachedGeometry::Poly { start, count }
+            }
+        };
+
+        ShapeState {
+            shape,
+            bb: (*shape).bb,
+            geometry,
+        }
+    }
+
+    // Returns false if the shape's vertexes can't be restored, as it was given a different number of vertexes.
+    unsafe fn matches(&self) -> bool {
+        match self.geometry {
+            CachedGeometry::Poly { count, .. } => {
+                (*(self.shape as *mut sys::cpPolyShape)).count as usize == count
+            }
+            _ => true,
+        }
+    }
+
+    unsafe fn restore(&self, planes: &[sys::cpSplittingPlane]) {
+        (*self.shape).bb = self.bb;
+        match self.geometry {
+            CachedGeometry::Circle { tc } => (*(self.shape as *mut sys::cpCircleShape)).tc = tc,
+            CachedGeometry::Segment { ta, tb, tn } => {
+                let segment = &mut *(self.shape as *mut sys::cpSegmentShape);
+                segment.ta = ta;
+                segment.tb = tb;
+                segment.tn = tn;
+            }
+            CachedGeometry::Poly { start, count } => {
+                // restores the local vertexes as well, in case they were changed with an unsafe setter
+                let poly = &mut *(self.shape as *mut sys::cpPolyShape);
+                let saved = &planes[start..start + 2 * count];
+                slice::from_raw_parts_mut(poly.planes, 2 * count).copy_from_slice(saved);
+            }
+        }
+    }
+}
+
+impl ArbiterState {
+    unsafe fn save(arbiter: *mut sys::cpArbiter, sleeping: bool) -> ArbiterState {
+        let saved = *arbiter;
+        let mut contacts: [sys::cpContact; MAX_CONTACTS] = mem::zeroed();
+        if !saved.contacts.is_null() {
+            let count = saved.count as usize;
+            contacts[..count].copy_from_slice(slice::from_raw_parts(saved.contacts, count));
+        }
+
+        ArbiterState {
+            arbiter,
+            saved,
+            contacts,
+            threads: [None; 4],
+            sleeping,
+        }
+    }
+
+    // Recreates the arbiter from the space's pool, leaving the pointers to other arbiters to the caller.
+    unsafe fn restore(&self, space: *mut sys::cpSpace) -> *mut sys::cpArbiter {
+        let arbiter = pooled_arbiter(space);
+        *arbiter = self.saved;
+
+        if !self.saved.contacts.is_null() {
+            let count = self.saved.count as usize;
+            let contacts = if self.sleeping {
+                let bytes = count * mem::size_of::<sys::cpContact>();
+                sys::cpcalloc(1, bytes) as *mut sys::cpContact
+            } else {
+                sys::cpContactBufferGetArray(space)
+            };
+            ptr::copy_nonoverlapping(self.contacts.as_ptr(), contacts, count);
+            if !self.sleeping {
+                sys::cpSpacePushContacts(space, count as i32);
+            }
+            (*arbiter).contacts = contacts;
+        }
+
+        arbiter
+    }
+}
+
+// Takes an arbiter from the space's pool, allocating more when it's empty, like cpSpaceArbiterSetTrans().
+unsafe fn pooled_arbiter(space: *mut sys::cpSpace) -> *mut sys::cpArbiter {
+    let pool = (*space).pooledArbiters;
+    if (*pool).num == 0 {
+        let count = sys::CP_BUFFER_BYTES as usize / mem::size_of::<sys::cpArbiter>();
+        let buffer = sys::cpcalloc(1, sys::CP_BUFFER_BYTES as usize) as *mut sys::cpArbiter;
+        sys::cpArrayPush((*space).allocatedBuffers, buffer as *mut c_void);
+        for i in 0..count {
+            sys::cpArrayPush(pool, buffer.add(i) as *mut c_void);
+        }
+    }
+    sys::cpArrayPop(pool) as *mut sys::cpArbiter
+}
+
+// Returns every arbiter in the space to its pool, so the saved ones can be recreated.
+unsafe fn release_arbiters(space: *mut sys::cpSpace) {
+    extern "C" fn release_cached_arbiter(arbiter: *mut c_void, space: *mut c_void) -> sys::cpBool {
+        let space = space as *mut sys::cpSpace;
+        unsafe { sys::cpArrayPush((*space).pooledArbiters, arbiter) };
+        0
+    }
+
+    sys::cpHashSetFilter(
+        (*space).cachedArbiters,
+        Some(release_cached_arbiter),
+        space as *mut c_void,
+    );
+    each_sleeping_arbiter(space, |arbiter| {
+        sys::cpfree((*arbiter).contacts as *mut c_void);
+        sys::cpArrayPush((*space).pooledArbiters, arbiter as *mut c_void);
+    });
+
+    (*(*space).arbiters).num = 0;
+    each_space_body(space, |body| (*body).arbiterList = ptr::null_mut());
+}
+
+/// Remove every shape from the space's collision detection indexes and add them back in the order their bodies are stored.
+/// The structure of the indexes depends on how the shapes moved before, and it affects the order contacts are solved in,
+/// so rebuilding them makes later steps depend only on the restored state.
+unsafe fn rebuild_spatial_indexes(space: *mut sys::cpSpace) {
+    let (static_shapes, dynamic_shapes) = ((*space).staticShapes, (*space).dynamicShapes);
+    each_space_body(space, |body| {
+        each_body_shape(body, |shape| {
+            let (object, hashid) = (shape as *mut c_void, (*shape).hashid);
+            if sys::cpSpatialIndexContains(static_shapes, object, hashid) != 0 {
+                sys::cpSpatialIndexRemove(static_shapes, object, hashid);
+            } else {
+                sys::cpSpatialIndexRemove(dynamic_shapes, object, hashid);
+            }
+        });
+    });
+
+    // the shapes of sleeping and static bodies are kept in the static index, like cpSpaceDeactivateBody() does
+    each_inactive_body(space, |body| {
+        each_body_shape(body, |shape| {
+            sys::cpSpatialIndexInsert(static_shapes, shape as *mut c_void, (*shape).hashid);
+        });
+    });
+    for &body in array::<sys::cpBody>((*space).dynamicBodies) {
+        each_body_shape(body, |shape| {
+            sys::cpSpatialIndexInsert(dynamic_shapes, shape as *mut c_void, (*shape).hashid);
+        });
+    }
+}
+
+// Calls func for every body in the space including its static body, awake bodies first.
+unsafe fn each_space_body<F>(space: *mut sys::cpSpace, mut func: F)
+where
+    F: FnMut(*mut sys::cpBody),
+{
+    for &body in array::<sys::cpBody>((*space).dynamicBodies) {
+        func(body);
+    }
+    each_inactive_body(space, func);
+}
+
+// Calls func for the sleeping and static bodies in the space, including its static body.
+unsafe fn each_inactive_body<F>(space: *mut sys::cpSpace, mut func: F)
+where
+    F: FnMut(*mut sys::cpBody),
+{
+    for &root in array::<sys::cpBody>((*space).sleepingComponents) {
+        let mut body = root;
+        while !body.is_null() {
+            let next = (*body).sleeping.next;
+            func(body);
+            body = next;
+        }
+    }
+    for &body in array::<sys::cpBody>((*space).staticBodies) {
+        func(body);
+    }
+    func((*space).staticBody);
+}
+
+// Calls func once for every constraint in the space, including those between sleeping bodies.
+unsafe fn each_space_constraint<F>(space: *mut sys::cpSpace, mut func: F)
+where
+    F: FnMut(*mut sys::cpConstraint),
+{
+    // each constraint is found through body A, or through body B when body A isn't added to the space
+    each_space_body(space, |body| {
+        each_body_constraint(body, |constraint| {
+            let body_a = (*constraint).a;
+            if body_a == body || (*body_a).space != space {
+                func(constraint);
+            }
+        });
+    });
+    // a constraint between bodies that aren't added to the space can't sleep, so it's in the list of active constraints
+    for &constraint in array::<sys::cpConstraint>((*space).constraints) {
+        if (*(*constraint).a).space != space && (*(*constraint).b).space != space {
+            func(constraint);
+        }
+    }
+}
+
+unsafe fn each_body_shape<F>(body: *mut sys::cpBody, mut func: F)
+where
+    F: FnMut(*mut sys::cpShape),
+{
+    let mut shape = (*body).shapeList;
+    while !shape.is_null() {
+        let next = (*shape).next;
+        func(shape);
+        shape = next;
+    }
+}
+
+// Calls func for each arbiter of the sleeping bodies, which are not in the space's cache.
+unsafe fn each_sleeping_arbiter<F>(space: *mut sys::cpSpace, mut func: F)
+where
+    F: FnMut(*mut sys::cpArbiter),
+{
+    for &root in array::<sys::cpBody>((*space).sleepingComponents) {
+        let mut body = root;
+        while !body.is_null() {
+            let mut arbiter = (*body).arbiterList;
+            while !arbiter.is_null() {
+                let body_a = (*arbiter).body_a;
+                // the list is threaded through both bodies of each arbiter, see cpArbiterNext()
+                let next = if body_a == body {
+                    (*arbiter).thread_a.next
+                } else {
+                    (*arbiter).thread_b.next
+                };
+                // each arbiter belongs to body A unless it's static, like in cpSpaceDeactivateBody()
+                if body_a == body
+                    || sys::cpBodyGetType(body_a) == sys::cpBodyType::CP_BODY_TYPE_STATIC
+                {
+                    func(arbiter);
+                }
+                arbiter = next;
+            }
+            body = (*body).sleeping.next;
+        }
+    }
+}
+
+fn arbiter_index(indexes: &[(usize, usize)], arbiter: *mut sys::cpArbiter) -> Option<usize> {
+    indexes
+        .binary_search_by_key(&(arbiter as usize), |&(arbiter, _)| arbiter)
+        .ok()
+        .map(|found| indexes[found].1)
+}
+
+unsafe fn array<'a, T>(arr: *mut sys::cpArray) -> &'a [*mut T] {
+    if (*arr).num == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts((*arr).arr as *const *mut T, (*arr).num as usize)
+    }
+}
+
+unsafe fn copy_array<T>(arr: *mut sys::cpArray, items: &mut Vec<*mut T>) {
+    items.clear();
+    items.extend_from_slice(array(arr));
+}
+
+unsafe fn set_array<T>(arr: *mut sys::cpArray, items: &[*mut T]) {
+    (*arr).num = 0;
+    for &item in items {
+        sys::cpArrayPush(arr, item as *mut c_void);
+    }
+}
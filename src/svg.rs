@@ -0,0 +1,247 @@
+extern crate chipmunk_sys as sys;
+
+use crate::bb::*;
+use crate::debug_draw::*;
+use crate::vect::*;
+use crate::{Body, Shape, ShapeKind};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Settings used when rendering a space to an SVG document.
+#[derive(Copy, Clone, Debug)]
+pub struct SvgOptions {
+    /// The region of the space, in world coordinates, that is visible in the document.
+    pub view: BB,
+    /// Number of SVG user units per world unit.
+    pub scale: Real,
+    /// Which parts of the space to draw.
+    pub flags: DebugDrawFlags,
+    /// Draw a marker at the position and rotation of each body.
+    pub draw_bodies: bool,
+    /// Color to fill the document with before drawing, if any.
+    pub background: Option<DebugColor>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions {
+            view: BB::new(-320.0, -240.0, 320.0, 240.0),
+            scale: 1.0,
+            flags: DebugDrawFlags::ALL,
+            draw_bodies: true,
+            background: Some(DebugColor::new(0.027, 0.212, 0.259, 1.0)),
+        }
+    }
+}
+
+/// A DebugDraw implementation that accumulates SVG elements.
+/// World coordinates are flipped vertically so that +y points up in the rendered image,
+/// matching Chipmunk's conventions.
+pub struct SvgRenderer {
+    options: SvgOptions,
+    body: String,
+}
+
+impl SvgRenderer {
+    pub fn new(options: SvgOptions) -> SvgRenderer {
+        SvgRenderer {
+            options,
+            body: String::new(),
+        }
+    }
+
+    /// Width of the document in SVG user units.
+    pub fn width(&self) -> Real {
+        (self.options.view.r() - self.options.view.l()) * self.options.scale
+    }
+
+    /// Height of the document in SVG user units.
+    pub fn height(&self) -> Real {
+        (self.options.view.t() - self.options.view.b()) * self.options.scale
+    }
+
+    fn x(&self, v: Vect) -> Real {
+        (v.x() - self.options.view.l()) * self.options.scale
+    }
+
+    fn y(&self, v: Vect) -> Real {
+        (self.options.view.t() - v.y()) * self.options.scale
+    }
+
+    /// Draw a marker showing a body's position and rotation.
+    pub fn draw_body(&mut self, body: &Body) {
+        let p = body.position();
+        let rot = body.rotation();
+        let size = 4.0 / self.options.scale;
+        let color = DebugColor::new(1.0, 1.0, 1.0, 0.75);
+
+        self.draw_segment(p - rot * size, p + rot * (size * 2.0), color);
+        self.draw_segment(p - rot.perp() * size, p + rot.perp() * size, color);
+    }
+
+    /// Finish the document and return its text.
+    pub fn finish(self) -> String {
+        let width = self.width();
+        let height = self.height();
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        );
+        if let Some(background) = self.options.background {
+            let _ = writeln!(
+                svg,
+                r#"<rect width="{}" height="{}" {}/>"#,
+                width,
+                height,
+                paint("fill", background)
+            );
+        }
+        svg.push_str(&self.body);
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Convert a color component to the 0-255 range, rounding to the nearest value.
+fn channel(value: f32) -> u8 {
+    // adding 0.5 rounds because the value is never negative, and f32::round() needs std
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Format @c color as an SVG paint attribute and its matching opacity attribute.
+fn paint(attribute: &str, color: DebugColor) -> String {
+    format!(
+        r#"{0}="rgb({1},{2},{3})" {0}-opacity="{4}""#,
+        attribute,
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        color.a.clamp(0.0, 1.0)
+    )
+}
+
+impl DebugDraw for SvgRenderer {
+    fn draw_circle(
+        &mut self,
+        position: Vect,
+        angle: Real,
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    ) {
+        let edge = position + Vect::from_angle(angle) * radius;
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{}" cy="{}" r="{}" {} {}/>"#,
+            self.x(position),
+            self.y(position),
+            radius * self.options.scale,
+            paint("fill", fill_color),
+            paint("stroke", outline_color)
+        );
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {}/>"#,
+            self.x(position),
+            self.y(position),
+            self.x(edge),
+            self.y(edge),
+            paint("stroke", outline_color)
+        );
+    }
+
+    fn draw_segment(&mut self, a: Vect, b: Vect, color: DebugColor) {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {}/>"#,
+            self.x(a),
+            self.y(a),
+            self.x(b),
+            self.y(b),
+            paint("stroke", color)
+        );
+    }
+
+    fn draw_fat_segment(
+        &mut self,
+        a: Vect,
+        b: Vect,
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    ) {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke-width="{}" stroke-linecap="round" {}/>"#,
+            self.x(a),
+            self.y(a),
+            self.x(b),
+            self.y(b),
+            (2.0 * radius * self.options.scale).max(1.0),
+            paint("stroke", fill_color)
+        );
+        self.draw_segment(a, b, outline_color);
+    }
+
+    fn draw_polygon(
+        &mut self,
+        verts: &[Vect],
+        radius: Real,
+        outline_color: DebugColor,
+        fill_color: DebugColor,
+    ) {
+        let mut points = String::new();
+        for v in verts {
+            let _ = write!(points, "{},{} ", self.x(*v), self.y(*v));
+        }
+
+        let _ = writeln!(
+            self.body,
+            r#"<polygon points="{}" stroke-width="{}" stroke-linejoin="round" {} {}/>"#,
+            points.trim_end(),
+            (2.0 * radius * self.options.scale).max(1.0),
+            paint("fill", fill_color),
+            paint("stroke", outline_color)
+        );
+    }
+
+    fn draw_dot(&mut self, size: Real, position: Vect, color: DebugColor) {
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{}" cy="{}" r="{}" {}/>"#,
+            self.x(position),
+            self.y(position),
+            size * 0.5,
+            paint("fill", color)
+        );
+    }
+
+    /// Colors shapes by their type, dimming static shapes and greying out sleeping ones.
+    fn color_for_shape(&mut self, shape: &Shape) -> DebugColor {
+        unsafe {
+            let body = (*shape.0).body;
+
+            let mut color = match shape.kind() {
+                ShapeKind::Circle => DebugColor::new(0.149, 0.545, 0.824, 1.0),
+                ShapeKind::Segment => DebugColor::new(0.796, 0.294, 0.086, 1.0),
+                ShapeKind::Poly => DebugColor::new(0.522, 0.600, 0.000, 1.0),
+            };
+
+            if sys::cpBodyGetType(body) == sys::cpBodyType::CP_BODY_TYPE_STATIC {
+                color = DebugColor::gray(0.4, 1.0);
+            } else if sys::cpBodyIsSleeping(body) != 0 {
+                color = DebugColor::new(0.345, 0.431, 0.459, 1.0);
+            }
+
+            if (*shape.0).sensor != 0 {
+                color.a = 0.1;
+            }
+
+            color
+        }
+    }
+}
@@ -0,0 +1,177 @@
+extern crate chipmunk_sys as sys;
+
+use crate::bb::*;
+use crate::vect::*;
+use core::fmt;
+use core::ops::Mul;
+
+/// Column major affine transform.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Transform(pub sys::cpTransform);
+
+impl Transform {
+    /// Construct a new transform matrix.
+    /// (a, b) is the x basis vector.
+    /// (c, d) is the y basis vector.
+    /// (tx, ty) is the translation.
+    pub fn new(a: Real, b: Real, c: Real, d: Real, tx: Real, ty: Real) -> Transform {
+        Transform(sys::cpTransform { a, b, c, d, tx, ty })
+    }
+
+    /// Construct a new transform matrix in transposed order.
+    pub fn new_transpose(a: Real, c: Real, tx: Real, b: Real, d: Real, ty: Real) -> Transform {
+        Transform::new(a, b, c, d, tx, ty)
+    }
+
+    /// Identity transform matrix.
+    pub fn identity() -> Transform {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Create a transation matrix.
+    pub fn translate(translate: Vect) -> Transform {
+        Transform::new_transpose(1.0, 0.0, translate.x(), 0.0, 1.0, translate.y())
+    }
+
+    /// Create a scale matrix.
+    pub fn scale(scale_x: Real, scale_y: Real) -> Transform {
+        Transform::new_transpose(scale_x, 0.0, 0.0, 0.0, scale_y, 0.0)
+    }
+
+    /// Create a rotation matrix.
+    pub fn rotate(radians: Real) -> Transform {
+        let rot = Vect::from_angle(radians);
+        Transform::new_transpose(rot.x(), -rot.y(), 0.0, rot.y(), rot.x(), 0.0)
+    }
+
+    /// Create a rigid transformation matrix. (transation + rotation)
+    pub fn rigid(translate: Vect, radians: Real) -> Transform {
+        let rot = Vect::from_angle(radians);
+        Transform::new_transpose(
+            rot.x(),
+            -rot.y(),
+            translate.x(),
+            rot.y(),
+            rot.x(),
+            translate.y(),
+        )
+    }
+
+    /// Get the inverse of a transform matrix.
+    pub fn inverse(self) -> Transform {
+        let t = self.0;
+        let inv_det = 1.0 / (t.a * t.d - t.c * t.b);
+        Transform::new_transpose(
+            t.d * inv_det,
+            -t.c * inv_det,
+            (t.c * t.ty - t.tx * t.d) * inv_det,
+            -t.b * inv_det,
+            t.a * inv_det,
+            (t.tx * t.b - t.a * t.ty) * inv_det,
+        )
+    }
+
+    /// Fast inverse of a rigid transformation matrix.
+    pub fn rigid_inverse(self) -> Transform {
+        let t = self.0;
+        Transform::new_transpose(
+            t.d,
+            -t.c,
+            t.c * t.ty - t.tx * t.d,
+            -t.b,
+            t.a,
+            t.tx * t.b - t.a * t.ty,
+        )
+    }
+
+    /// Multiply two transformation matrices.
+    pub fn mult(self, other: Transform) -> Transform {
+        let (t1, t2) = (self.0, other.0);
+        Transform::new_transpose(
+            t1.a * t2.a + t1.c * t2.b,
+            t1.a * t2.c + t1.c * t2.d,
+            t1.a * t2.tx + t1.c * t2.ty + t1.tx,
+            t1.b * t2.a + t1.d * t2.b,
+            t1.b * t2.c + t1.d * t2.d,
+            t1.b * t2.tx + t1.d * t2.ty + t1.ty,
+        )
+    }
+
+    /// Transform an absolute point. (i.e. a vertex)
+    pub fn transform_point(self, p: Vect) -> Vect {
+        let t = self.0;
+        Vect::new(
+            t.a * p.x() + t.c * p.y() + t.tx,
+            t.b * p.x() + t.d * p.y() + t.ty,
+        )
+    }
+
+    /// Transform a vector (i.e. a normal)
+    pub fn transform_vect(self, v: Vect) -> Vect {
+        let t = self.0;
+        Vect::new(t.a * v.x() + t.c * v.y(), t.b * v.x() + t.d * v.y())
+    }
+
+    /// Transform a cpBB.
+    pub fn transform_bb(self, bb: BB) -> BB {
+        let t = self.0;
+        let center = bb.center();
+        let hw = (bb.r() - bb.l()) * 0.5;
+        let hh = (bb.t() - bb.b()) * 0.5;
+
+        let (a, b, d, e) = (t.a * hw, t.c * hh, t.b * hw, t.d * hh);
+        let hw_max = (a + b).abs().max((a - b).abs());
+        let hh_max = (d + e).abs().max((d - e).abs());
+        BB::new_for_extents(self.transform_point(center), hw_max, hh_max)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform::identity()
+    }
+}
+
+impl From<sys::cpTransform> for Transform {
+    fn from(t: sys::cpTransform) -> Self {
+        Transform(t)
+    }
+}
+
+impl From<Transform> for sys::cpTransform {
+    fn from(t: Transform) -> Self {
+        t.0
+    }
+}
+
+impl fmt::Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cpTransform")
+            .field("a", &self.0.a)
+            .field("b", &self.0.b)
+            .field("c", &self.0.c)
+            .field("d", &self.0.d)
+            .field("tx", &self.0.tx)
+            .field("ty", &self.0.ty)
+            .finish()
+    }
+}
+
+/// Multiply two transformation matrices.
+impl Mul for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Transform {
+        self.mult(rhs)
+    }
+}
+
+/// Transform an absolute point.
+impl Mul<Vect> for Transform {
+    type Output = Vect;
+
+    fn mul(self, rhs: Vect) -> Vect {
+        self.transform_point(rhs)
+    }
+}
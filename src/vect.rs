@@ -1,14 +1,19 @@
 extern crate chipmunk_sys as sys;
 
-use std::fmt;
-use std::ops::{Add, Mul, Neg, Sub};
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Chipmunk's floating point type, matching cpFloat.
+/// It is f32 with the "f32" feature and f64 otherwise.
+pub type Real = sys::cpFloat;
 
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct Vect(pub sys::cpVect);
 
 impl Vect {
     /// Convenience constructor for cpVect structs.
-    pub fn new(x: f64, y: f64) -> Vect {
+    pub fn new(x: Real, y: Real) -> Vect {
         Vect(sys::cpVect { x, y })
     }
 
@@ -18,38 +23,36 @@ impl Vect {
     }
 
     /// Returns the unit length vector for the given angle (in radians).
-    pub fn from_angle(radians: f64) -> Vect {
-        Vect(sys::cpVect {
-            x: radians.cos(),
-            y: radians.sin(),
-        })
+    pub fn from_angle(radians: Real) -> Vect {
+        // the shim in chipmunk-sys provides the trig functions, which core lacks
+        Vect(unsafe { sys::cpvforangle(radians) })
     }
 
-    pub fn x(&self) -> f64 {
+    pub fn x(&self) -> Real {
         self.0.x
     }
 
-    pub fn set_x(&mut self, x: f64) {
+    pub fn set_x(&mut self, x: Real) {
         self.0.x = x;
     }
 
-    pub fn y(&self) -> f64 {
+    pub fn y(&self) -> Real {
         self.0.y
     }
 
-    pub fn set_y(&mut self, y: f64) {
+    pub fn set_y(&mut self, y: Real) {
         self.0.y = y;
     }
 
     /// Vector dot product.
-    pub fn dot(self, rhs: Vect) -> f64 {
+    pub fn dot(self, rhs: Vect) -> Real {
         self.0.x * rhs.0.x + self.0.y * rhs.0.y
     }
 
     /// 2D vector cross product analog.
     /// The cross product of 2D vectors results in a 3D vector with only a z component.
     /// This function returns the magnitude of the z value.
-    pub fn cross(self, rhs: Vect) -> f64 {
+    pub fn cross(self, rhs: Vect) -> Real {
         self.0.x * rhs.0.y - self.0.y * rhs.0.x
     }
 
@@ -80,13 +83,13 @@ impl Vect {
     }
 
     /// Returns the squared length of v. Faster than cpvlength() when you only need to compare lengths.
-    pub fn length_squared(self) -> f64 {
+    pub fn length_squared(self) -> Real {
         self.dot(self)
     }
 
     /// Returns the length of v.
-    pub fn length(self) -> f64 {
-        self.dot(self).sqrt()
+    pub fn length(self) -> Real {
+        unsafe { sys::cpvlength(self.0) }
     }
 }
 
@@ -108,6 +111,12 @@ impl From<Vect> for sys::cpVect {
     }
 }
 
+impl PartialEq for Vect {
+    fn eq(&self, other: &Vect) -> bool {
+        self.0.x == other.0.x && self.0.y == other.0.y
+    }
+}
+
 impl fmt::Debug for Vect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("cpVect")
@@ -145,16 +154,16 @@ impl Sub for Vect {
 }
 
 /// Scalar multiplication.
-impl Mul<f64> for Vect {
+impl Mul<Real> for Vect {
     type Output = Vect;
 
-    fn mul(self, rhs: f64) -> Vect {
+    fn mul(self, rhs: Real) -> Vect {
         Vect::new(self.0.x * rhs, self.0.y * rhs)
     }
 }
 
 /// Scalar multiplication.
-impl Mul<Vect> for f64 {
+impl Mul<Vect> for Real {
     type Output = Vect;
 
     fn mul(self, rhs: Vect) -> Vect {
@@ -0,0 +1,60 @@
+extern crate chipmunk_sys as sys;
+
+use crate::error::*;
+use alloc::format;
+use alloc::string::String;
+use core::ffi::CStr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// The version of the Chipmunk headers these bindings were generated from.
+pub fn header_version() -> String {
+    format!(
+        "{}.{}.{}",
+        sys::CP_VERSION_MAJOR,
+        sys::CP_VERSION_MINOR,
+        sys::CP_VERSION_RELEASE
+    )
+}
+
+/// The version of the Chipmunk library that was linked.
+pub fn version() -> &'static str {
+    unsafe { CStr::from_ptr(sys::cpVersionString) }
+        .to_str()
+        .unwrap_or("unknown")
+}
+
+/// Check that the linked Chipmunk library is the same version as the headers.
+/// The wrappers read some of Chipmunk's structs directly, so a different version can't be used safely.
+/// Creating a body or space runs this check and panics if it fails, and Body::try_new() returns the error instead.
+/// Call this first to report a mismatched library in your own way.
+pub fn check_version() -> Result<(), ChipmunkError> {
+    let linked = version();
+    let header = [
+        sys::CP_VERSION_MAJOR,
+        sys::CP_VERSION_MINOR,
+        sys::CP_VERSION_RELEASE,
+    ];
+    let mut parts = linked.split('.').map(|part| part.parse::<u32>().ok());
+    let matches = header
+        .iter()
+        .all(|&expected| parts.next() == Some(Some(expected)))
+        && parts.next().is_none();
+
+    if matches {
+        Ok(())
+    } else {
+        Err(ChipmunkError::VersionMismatch(linked))
+    }
+}
+
+/// Panics if check_version() fails. The check is skipped once it has passed.
+pub(crate) fn assert_version() {
+    // racing threads may both run the check, which is harmless
+    static CHECKED: AtomicBool = AtomicBool::new(false);
+    if !CHECKED.load(Ordering::Relaxed) {
+        if let Err(err) = check_version() {
+            panic!("{}", err);
+        }
+        CHECKED.store(true, Ordering::Relaxed);
+    }
+}
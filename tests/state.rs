@@ -0,0 +1,252 @@
+#![cfg(feature = "state")]
+
+extern crate chipmunk;
+
+use chipmunk::*;
+
+const DT: Real = 1.0 / 60.0;
+
+// The objects are declared before the space in each test, so the space is freed first.
+struct Scene {
+    bodies: Vec<Body>,
+    shapes: Vec<Shape>,
+    constraints: Vec<Constraint>,
+    pendulum: usize,
+    sleeper: usize,
+}
+
+impl Scene {
+    fn new() -> Scene {
+        Scene {
+            bodies: Vec::new(),
+            shapes: Vec::new(),
+            constraints: Vec::new(),
+            pendulum: 0,
+            sleeper: 0,
+        }
+    }
+
+    fn add_box(&mut self, space: &mut Space, position: Vect) -> usize {
+        let mut body = Body::new(1.0, moment_for_box(1.0, 10.0, 10.0));
+        body.set_position(position);
+        space.add_body(&body);
+
+        let mut shape = Shape::poly_box(&body, 10.0, 10.0, 0.0);
+        shape.set_friction(0.7);
+        space.add_shape(&shape);
+
+        self.bodies.push(body);
+        self.shapes.push(shape);
+        self.bodies.len() - 1
+    }
+
+    // A stack of boxes and a pendulum, both still moving after settle(), and a box that is put to sleep on the ground
+    // with a ball falling onto it, which wakes it up a while after settle().
+    fn build(&mut self, space: &mut Space) {
+        space.set_gravity(Vect::new(0.0, -100.0));
+        space.set_iterations(10);
+        space.set_sleep_time_threshold(0.5);
+
+        let mut ground = Shape::segment(
+            &space.static_body(),
+            Vect::new(-200.0, 0.0),
+            Vect::new(200.0, 0.0),
+            1.0,
+        );
+        ground.set_friction(1.0);
+        space.add_shape(&ground);
+        self.shapes.push(ground);
+
+        for i in 0..5 {
+            let offset = i as Real * 1.5;
+            self.add_box(space, Vect::new(-100.0 + offset, 6.0 + i as Real * 11.0));
+        }
+
+        self.pendulum = self.add_box(space, Vect::new(60.0, 80.0));
+        let pivot = ConstraintDef::new(JointDef::PivotJoint {
+            anchor_a: Vect::new(0.0, 120.0),
+            anchor_b: Vect::new(20.0, 0.0),
+        })
+        .build(&space.static_body(), &self.bodies[self.pendulum]);
+        space.add_constraint(pivot.0);
+        self.constraints.push(pivot);
+
+        self.sleeper = self.add_box(space, Vect::new(150.0, 6.0));
+
+        let mut ball = Body::new(1.0, moment_for_circle(1.0, 0.0, 5.0, Vect::new(0.0, 0.0)));
+        ball.set_position(Vect::new(151.0, 200.0));
+        space.add_body(&ball);
+        let ball_shape = Shape::circle(&ball, 5.0, Vect::new(0.0, 0.0));
+        space.add_shape(&ball_shape);
+        self.bodies.push(ball);
+        self.shapes.push(ball_shape);
+    }
+
+    fn settle(&mut self, space: &mut Space) {
+        for _ in 0..60 {
+            space.step(DT);
+        }
+
+        let sleeper = &mut self.bodies[self.sleeper];
+        sleeper.sleep();
+        assert!(sleeper.is_sleeping());
+        // the arbiter with the ground was moved out of the space's cache when the box fell asleep
+        assert!(unsafe { !(*sleeper.0).arbiterList.is_null() });
+        assert!(unsafe { (*(*space.0).arbiters).num } > 0);
+    }
+
+    // The pose, velocity and sleep state of every body.
+    fn values(&mut self) -> Vec<Real> {
+        let mut values = Vec::new();
+        for body in &mut self.bodies {
+            values.extend_from_slice(&[
+                body.position().x(),
+                body.position().y(),
+                body.angle(),
+                body.velocity().x(),
+                body.velocity().y(),
+                body.angular_velocity(),
+                if body.is_sleeping() { 1.0 } else { 0.0 },
+            ]);
+        }
+        values
+    }
+
+    // The values of every body after each of @c steps steps.
+    fn record(&mut self, space: &mut Space, steps: usize) -> Vec<Vec<Real>> {
+        (0..steps)
+            .map(|_| {
+                space.step(DT);
+                self.values()
+            })
+            .collect()
+    }
+}
+
+// Compares the bits of the values, as the steps must be exactly the same.
+fn same_steps(a: &[Vec<Real>], b: &[Vec<Real>]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.to_bits() == b.to_bits())
+        })
+}
+
+#[test]
+fn save_leaves_the_space_unchanged() {
+    let mut scene = Scene::new();
+    let mut space = Space::new();
+    scene.build(&mut space);
+    scene.settle(&mut space);
+
+    let mut saved_scene = Scene::new();
+    let mut saved_space = Space::new();
+    saved_scene.build(&mut saved_space);
+    saved_scene.settle(&mut saved_space);
+
+    // the spaces are stepped in lockstep, and only one of them is saved, twice
+    let mut state = SpaceState::new();
+    state.save(&mut saved_space);
+    let steps = scene.record(&mut space, 60);
+    assert!(same_steps(
+        &steps,
+        &saved_scene.record(&mut saved_space, 60)
+    ));
+
+    state.save(&mut saved_space);
+    let steps = scene.record(&mut space, 60);
+    assert!(same_steps(
+        &steps,
+        &saved_scene.record(&mut saved_space, 60)
+    ));
+}
+
+#[test]
+fn restore_reproduces_steps() {
+    let mut scene = Scene::new();
+    let mut space = Space::new();
+    scene.build(&mut space);
+    scene.settle(&mut space);
+
+    let mut state = SpaceState::new();
+    state.save(&mut space);
+    let values = scene.values();
+    scene.record(&mut space, 120);
+    // the ball has woken the sleeping box, so the steps went through waking its cached arbiter
+    assert!(!scene.bodies[scene.sleeper].is_sleeping());
+
+    // the steps after a restore only depend on the state, not on the steps taken after saving it
+    state.restore(&mut space).unwrap();
+    assert!(same_steps(&[values], &[scene.values()]));
+    assert!(scene.bodies[scene.sleeper].is_sleeping());
+    let restored = scene.record(&mut space, 120);
+
+    // restoring doesn't use up the state
+    state.restore(&mut space).unwrap();
+    assert!(same_steps(&restored, &scene.record(&mut space, 120)));
+}
+
+#[test]
+fn restore_in_lockstep() {
+    let mut scene = Scene::new();
+    let mut space = Space::new();
+    scene.build(&mut space);
+    scene.settle(&mut space);
+
+    let mut other_scene = Scene::new();
+    let mut other_space = Space::new();
+    other_scene.build(&mut other_space);
+    other_scene.settle(&mut other_space);
+
+    // the spaces take different steps after saving, and step the same way again once both are restored
+    let mut state = SpaceState::new();
+    let mut other_state = SpaceState::new();
+    state.save(&mut space);
+    other_state.save(&mut other_space);
+    scene.record(&mut space, 30);
+    other_scene.record(&mut other_space, 90);
+
+    state.restore(&mut space).unwrap();
+    other_state.restore(&mut other_space).unwrap();
+    let steps = scene.record(&mut space, 120);
+    assert!(same_steps(
+        &steps,
+        &other_scene.record(&mut other_space, 120)
+    ));
+}
+
+#[test]
+fn restore_after_saving_again() {
+    let mut scene = Scene::new();
+    let mut space = Space::new();
+    scene.build(&mut space);
+    scene.settle(&mut space);
+
+    let mut state = SpaceState::new();
+    state.save(&mut space);
+    scene.record(&mut space, 30);
+
+    // saving again reuses the buffers, and the new state replaces the old one
+    state.save(&mut space);
+    let values = scene.values();
+    scene.record(&mut space, 30);
+    state.restore(&mut space).unwrap();
+    assert!(same_steps(&[values], &[scene.values()]));
+}
+
+#[test]
+fn restore_checks_the_space() {
+    let mut scene = Scene::new();
+    let mut space = Space::new();
+    let mut other_space = Space::new();
+    scene.build(&mut space);
+
+    let mut state = SpaceState::new();
+    state.save(&mut space);
+    assert_eq!(
+        state.restore(&mut other_space),
+        Err(ChipmunkError::StateMismatch)
+    );
+
+    scene.add_box(&mut space, Vect::new(0.0, 100.0));
+    assert_eq!(state.restore(&mut space), Err(ChipmunkError::StateMismatch));
+}